@@ -0,0 +1,83 @@
+//! The derive behind `common::line::FromLine`. Use it through `common`,
+//! which provides what the generated code needs.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use regex::Regex;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+#[proc_macro_derive(FromLine, attributes(pattern))]
+pub fn derive_from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("pattern"))
+        .ok_or_else(|| Error::new_spanned(name, "expected a `#[pattern(r\"...\")]` attribute"))?;
+    let pattern: LitStr = attr.parse_args()?;
+    // Check the pattern now, so a typo fails the build rather than a run.
+    let regex = Regex::new(&pattern.value()).map_err(|err| Error::new_spanned(&pattern, err))?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(name, "FromLine needs named fields")),
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                name,
+                "FromLine can only be derived for structs",
+            ))
+        }
+    };
+    let mut inits = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let group = ident.to_string();
+        if !regex.capture_names().flatten().any(|name| name == group) {
+            return Err(Error::new_spanned(
+                ident,
+                format!("the pattern has no `(?P<{}>...)` group", group),
+            ));
+        }
+        inits.push(quote! { #ident: ::common::line::field(line, &captures, #group)? });
+    }
+
+    let anchored = format!("^(?:{})$", pattern.value());
+    let expected = format!("expected a line matching `{}`", pattern.value());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Parses `line`, or returns `None` if it doesn't match the pattern.
+            pub fn from_line(
+                line: &str,
+            ) -> ::std::option::Option<::std::result::Result<Self, ::common::error::AocError>> {
+                static REGEX: ::std::sync::OnceLock<::common::line::Regex> =
+                    ::std::sync::OnceLock::new();
+                let regex = REGEX.get_or_init(|| ::common::line::Regex::new(#anchored).unwrap());
+                let captures = regex.captures(line)?;
+                let parse = || -> ::std::result::Result<Self, ::common::error::AocError> {
+                    ::std::result::Result::Ok(#name { #(#inits,)* })
+                };
+                ::std::option::Option::Some(parse())
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::common::error::AocError;
+
+            fn from_str(line: &str) -> ::std::result::Result<Self, Self::Err> {
+                Self::from_line(line)
+                    .unwrap_or_else(|| ::std::result::Result::Err(::common::error::AocError::parse(#expected)))
+            }
+        }
+    })
+}
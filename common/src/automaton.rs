@@ -0,0 +1,196 @@
+use crate::grid::{Grid, Position};
+use std::ops::Range;
+
+/// Where a cellular automaton's cells live.
+pub trait Space: Sized {
+    type Position: Copy;
+    type State: Copy + PartialEq;
+
+    /// Every position whose state might change in the next generation.
+    fn positions(&self) -> Vec<Self::Position>;
+
+    fn get(&self, pos: Self::Position) -> Self::State;
+
+    /// The next generation: this one, with `changes` applied.
+    fn update(&self, changes: Vec<(Self::Position, Self::State)>) -> Self;
+
+    /// Whether `positions` is the same every generation, so that each cell's
+    /// neighbours only need finding once.
+    fn is_fixed(&self) -> bool {
+        false
+    }
+}
+
+/// A fixed-size grid, where every cell can change.
+impl<T: Copy + PartialEq> Space for Grid<T> {
+    type Position = Position;
+    type State = T;
+
+    fn positions(&self) -> Vec<Position> {
+        Grid::positions(self).collect()
+    }
+
+    fn get(&self, pos: Position) -> T {
+        self[pos]
+    }
+
+    fn update(&self, changes: Vec<(Position, T)>) -> Grid<T> {
+        let mut next = self.clone();
+        for (pos, state) in changes {
+            next[pos] = state;
+        }
+        next
+    }
+
+    fn is_fixed(&self) -> bool {
+        true
+    }
+}
+
+/// Updates every cell of a space at once, each from its own state and its
+/// neighbours' states.
+///
+/// In a fixed space, `neighbors` is only asked about each position once, in
+/// the first generation, so it mustn't depend on states that change.
+pub struct Automaton<S: Space, N, R> {
+    pub space: S,
+    /// How many generations have been run.
+    pub generation: usize,
+    /// The positions whose states each cell's next state depends on.
+    neighbors: N,
+    /// A cell's next state, from its state and its neighbours' states.
+    rule: R,
+    /// Every position with the range of `neighborhoods` holding its
+    /// neighbours, kept between generations of a fixed space.
+    cells: Option<Vec<(S::Position, Range<usize>)>>,
+    neighborhoods: Vec<S::Position>,
+    /// Reused for each cell's neighbours' states.
+    states: Vec<S::State>,
+}
+
+impl<S, N, R> Automaton<S, N, R>
+where
+    S: Space,
+    N: Fn(&S, S::Position) -> Vec<S::Position>,
+    R: Fn(S::State, &[S::State]) -> S::State,
+{
+    pub fn new(space: S, neighbors: N, rule: R) -> Automaton<S, N, R> {
+        Automaton {
+            space,
+            generation: 0,
+            neighbors,
+            rule,
+            cells: None,
+            neighborhoods: Vec::new(),
+            states: Vec::new(),
+        }
+    }
+
+    /// Runs one generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        if self.cells.is_none() && self.space.is_fixed() {
+            let mut cells = Vec::new();
+            for pos in self.space.positions() {
+                let start = self.neighborhoods.len();
+                self.neighborhoods
+                    .extend((self.neighbors)(&self.space, pos));
+                cells.push((pos, start..self.neighborhoods.len()));
+            }
+            self.cells = Some(cells);
+        }
+
+        let Automaton {
+            space,
+            neighbors,
+            rule,
+            cells,
+            neighborhoods,
+            states,
+            ..
+        } = self;
+        let mut changes = Vec::new();
+        let mut visit = |pos: S::Position, cell_neighbors: &[S::Position]| {
+            let state = space.get(pos);
+            states.clear();
+            states.extend(cell_neighbors.iter().map(|&neighbor| space.get(neighbor)));
+            let next = rule(state, states);
+            if next != state {
+                changes.push((pos, next));
+            }
+        };
+        match cells {
+            Some(cells) => {
+                for (pos, range) in cells.iter() {
+                    visit(*pos, &neighborhoods[range.clone()]);
+                }
+            }
+            // A growing space's neighbourhoods change, so find them afresh.
+            None => {
+                for pos in space.positions() {
+                    visit(pos, &neighbors(space, pos));
+                }
+            }
+        }
+
+        let changed = !changes.is_empty();
+        self.space = self.space.update(changes);
+        self.generation += 1;
+        changed
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Runs until a generation changes nothing, returning how many
+    /// generations did change something.
+    pub fn run_until_stable(&mut self) -> usize {
+        let mut changed = 0;
+        while self.step() {
+            changed += 1;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::records::Section;
+
+    fn life(text: &str) -> Grid<bool> {
+        let section = Section {
+            line: 1,
+            lines: text.lines().collect(),
+        };
+        Grid::parse(&section, |ch| Some(ch == '#')).unwrap()
+    }
+
+    fn conway(alive: bool, neighbors: &[bool]) -> bool {
+        let alive_neighbors = neighbors.iter().filter(|&&n| n).count();
+        alive_neighbors == 3 || (alive && alive_neighbors == 2)
+    }
+
+    #[test]
+    fn blinker() {
+        let start = life(".....\n..#..\n..#..\n..#..\n.....");
+        let neighbors = |grid: &Grid<bool>, pos| grid.neighbors8(pos).collect();
+        let mut automaton = Automaton::new(start.clone(), neighbors, conway);
+        automaton.run(1);
+        assert_eq!(automaton.space, life(".....\n.....\n.###.\n.....\n....."));
+        automaton.run(1);
+        assert_eq!(automaton.space, start);
+        assert_eq!(automaton.generation, 2);
+    }
+
+    #[test]
+    fn block_is_stable() {
+        let start = life("....\n.##.\n.#..\n....");
+        let neighbors = |grid: &Grid<bool>, pos| grid.neighbors8(pos).collect();
+        let mut automaton = Automaton::new(start, neighbors, conway);
+        assert_eq!(automaton.run_until_stable(), 1);
+        assert_eq!(automaton.space, life("....\n.##.\n.##.\n...."));
+    }
+}
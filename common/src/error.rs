@@ -0,0 +1,235 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::result;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// Bad command-line arguments.
+    Usage,
+    /// The input couldn't be read.
+    Io,
+    /// The input doesn't match the puzzle's format.
+    Parse,
+    /// The input is well-formed, but has no answer.
+    NoSolution,
+    /// The solver hasn't been written yet.
+    Unsupported,
+    /// The solver panicked.
+    Panic,
+    /// The solver ran out of time.
+    Timeout,
+}
+
+/// The error type for every parser and solver in the workspace.
+///
+/// Parsers attach the line (and column, where known) of the offending text;
+/// whoever read the input attaches the file name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AocError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub file: Option<String>,
+    /// 1-based.
+    pub line: Option<usize>,
+    /// 1-based, counted in characters.
+    pub column: Option<usize>,
+    pub text: Option<String>,
+}
+
+pub type Result<T> = result::Result<T, AocError>;
+
+impl AocError {
+    pub fn new<S: Into<String>>(kind: ErrorKind, message: S) -> AocError {
+        AocError {
+            kind,
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    pub fn usage<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::Usage, message)
+    }
+
+    pub fn io<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::Io, message)
+    }
+
+    pub fn parse<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::Parse, message)
+    }
+
+    pub fn no_solution<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::NoSolution, message)
+    }
+
+    pub fn unsupported<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::Unsupported, message)
+    }
+
+    pub fn panic<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::Panic, message)
+    }
+
+    pub fn timeout<S: Into<String>>(message: S) -> AocError {
+        AocError::new(ErrorKind::Timeout, message)
+    }
+
+    /// Sets the file, unless a more specific one is already known.
+    pub fn in_file<S: Into<String>>(mut self, file: S) -> AocError {
+        self.file.get_or_insert_with(|| file.into());
+        self
+    }
+
+    /// Sets the line, unless a more specific one is already known.
+    pub fn at_line(mut self, line: usize) -> AocError {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column, unless a more specific one is already known.
+    pub fn at_column(mut self, column: usize) -> AocError {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Moves the column `offset` characters right, for errors found in part
+    /// of a line that starts that far in.
+    pub fn offset_column(mut self, offset: usize) -> AocError {
+        self.column = self.column.map(|column| column + offset);
+        self
+    }
+
+    /// Sets the offending text, unless a more specific one is already known.
+    pub fn with_text<S: Into<String>>(mut self, text: S) -> AocError {
+        self.text.get_or_insert_with(|| text.into());
+        self
+    }
+}
+
+impl Error for AocError {}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Compiler-style `file:line:column: message`, then the text itself.
+        let location: Vec<String> = vec![
+            self.file.clone(),
+            self.line.map(|line| line.to_string()),
+            self.line.and(self.column).map(|column| column.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, "\n  | {}", text)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  | {:>width$}", "^", width = column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(err: ParseIntError) -> AocError {
+        AocError::parse(format!("invalid number ({})", err))
+    }
+}
+
+/// Parses each line of `content` with `f`, pointing any error at its line.
+pub fn parse_lines<T, F>(content: &str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| f(line).map_err(|err| err.at_line(idx + 1).with_text(line)))
+        .collect()
+}
+
+/// Parses each `sep`-separated item of `line` with `f`, moving the columns of
+/// any error from the item to `line` (or pointing at the item if it has none).
+pub fn parse_list<T, F>(line: &str, sep: &str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    let mut start = 0;
+    line.split(sep)
+        .map(|item| {
+            let column = start;
+            start += item.chars().count() + sep.chars().count();
+            f(item).map_err(|err| err.offset_column(column).at_column(column + 1))
+        })
+        .collect()
+}
+
+/// Parses a number that starts at `column` (1-based) of its line.
+pub fn parse_number<T>(s: &str, column: usize) -> Result<T>
+where
+    T: std::str::FromStr<Err = ParseIntError>,
+{
+    s.parse::<T>()
+        .map_err(|err| AocError::from(err).at_column(column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_with_location() {
+        let err = AocError::parse("invalid value")
+            .at_column(5)
+            .at_line(3)
+            .with_text("jmp +x")
+            .in_file("input.txt");
+        assert_eq!(
+            err.to_string(),
+            "input.txt:3:5: invalid value\n  | jmp +x\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn display_without_location() {
+        assert_eq!(AocError::no_solution("no loop").to_string(), "no loop");
+    }
+
+    #[test]
+    fn innermost_context_wins() {
+        let err = AocError::parse("bad").at_line(2).at_line(7);
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        let err = parse_lines("1\n2\nthree\n", |line| parse_number::<u32>(line, 1)).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Parse);
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.column, Some(1));
+        assert_eq!(err.text.as_deref(), Some("three"));
+    }
+
+    #[test]
+    fn parse_list_reports_column() {
+        let parse = |line| parse_list(line, ", ", |item| parse_number::<u32>(item, 1));
+        assert_eq!(parse("1, 22, 3").unwrap(), vec![1, 22, 3]);
+        assert_eq!(parse("1, 22, x").unwrap_err().column, Some(8));
+
+        let err = parse_list("ab,é,c", ",", |item| match item {
+            "c" => Err(AocError::parse("no c")),
+            _ => Ok(()),
+        })
+        .unwrap_err();
+        assert_eq!(err.column, Some(6));
+    }
+}
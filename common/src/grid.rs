@@ -0,0 +1,313 @@
+use crate::error::AocError;
+use crate::records::Section;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A cell's `(x, y)`, counting from the top left.
+pub type Position = (usize, usize);
+
+/// A step of `(dx, dy)`; `y` grows downwards.
+pub type Direction = (isize, isize);
+
+/// Up, right, down and left.
+pub const ORTHOGONAL: [Direction; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Clockwise from up, including the diagonals.
+pub const ALL_DIRECTIONS: [Direction; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, addressed by `(x, y)` from the top left.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows differ in length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one cell per character, pointing any error at its line and column.
+    pub fn parse<F>(section: &Section<'_>, cell: F) -> Result<Grid<T>, AocError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let width = section.lines.first().map_or(0, |line| line.chars().count());
+        let rows = section.parse_lines(|line| {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(idx, ch)| {
+                    cell(ch).ok_or_else(|| {
+                        AocError::parse(format!("unexpected `{}`", ch)).at_column(idx + 1)
+                    })
+                })
+                .collect::<Result<Vec<T>, AocError>>()?;
+            if row.len() != width {
+                return Err(AocError::parse(format!(
+                    "expected {} cells, like the first row, found {}",
+                    width,
+                    row.len()
+                )));
+            }
+            Ok(row)
+        })?;
+        Ok(Grid::from_rows(rows))
+    }
+
+    /// Parses a whole input, one row per line.
+    pub fn parse_str<F>(content: &str, cell: F) -> Result<Grid<T>, AocError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let section = Section {
+            line: 1,
+            lines: content.lines().collect(),
+        };
+        Grid::parse(&section, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, but wrapping around at the edges, as if the grid repeated
+    /// forever in every direction.
+    pub fn get_wrapping(&self, x: usize, y: usize) -> &T {
+        &self[(x % self.width, y % self.height)]
+    }
+
+    /// Where a step from `pos` lands, unless it leaves the grid.
+    pub fn step(&self, (x, y): Position, (dx, dy): Direction) -> Option<Position> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// Where a step from `pos` lands, wrapping around at the edges.
+    pub fn step_wrapping(&self, (x, y): Position, (dx, dy): Direction) -> Position {
+        let wrap = |pos: usize, delta: isize, len: usize| {
+            (pos as isize + delta).rem_euclid(len as isize) as usize
+        };
+        (wrap(x, dx, self.width), wrap(y, dy, self.height))
+    }
+
+    /// The neighbours of `pos` above, below and to either side.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    /// The neighbours of `pos`, including diagonal ones.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    /// Every position from `pos` in `dir` to the edge, not including `pos`.
+    pub fn ray(&self, pos: Position, dir: Direction) -> impl Iterator<Item = Position> + '_ {
+        let mut pos = Some(pos);
+        std::iter::from_fn(move || {
+            pos = self.step(pos?, dir);
+            pos
+        })
+    }
+
+    /// Every position, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |idx| (idx % width, idx / width))
+    }
+
+    pub fn row(&self, y: usize) -> Vec<T> {
+        self.cells[y * self.width..(y + 1) * self.width].to_vec()
+    }
+
+    pub fn column(&self, x: usize) -> Vec<T> {
+        (0..self.height).map(|y| self[(x, y)].clone()).collect()
+    }
+
+    pub fn rows(&self) -> Vec<Vec<T>> {
+        (0..self.height).map(|y| self.row(y)).collect()
+    }
+
+    /// Every cell, in reading order.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate(&self) -> Grid<T> {
+        let rows = (0..self.width)
+            .map(|x| {
+                (0..self.height)
+                    .rev()
+                    .map(|y| self[(x, y)].clone())
+                    .collect()
+            })
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    /// Mirrors left to right.
+    pub fn flip(&self) -> Grid<T> {
+        let rows = self
+            .rows()
+            .into_iter()
+            .map(|row| row.into_iter().rev().collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    /// All 8 rotations and reflections, starting with this one unchanged.
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut orientations = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip()].iter() {
+            let mut grid = start.clone();
+            for _ in 0..4 {
+                let next = grid.rotate();
+                orientations.push(grid);
+                grid = next;
+            }
+        }
+        orientations
+    }
+
+    /// The `width` by `height` part of the grid whose top left is `(x, y)`.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<T> {
+        let rows = (y..y + height)
+            .map(|y| (x..x + width).map(|x| self[(x, y)].clone()).collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of bounds",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of bounds",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// One line per row, as in the puzzle inputs.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (idx, cell) in self.cells.iter().enumerate() {
+            if idx > 0 && idx % self.width == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", cell)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        let section = Section {
+            line: 1,
+            lines: text.lines().collect(),
+        };
+        Grid::parse(&section, Some).unwrap()
+    }
+
+    #[test]
+    fn rotate_and_flip() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!(g.rotate().to_string(), "eca\nfdb");
+        assert_eq!(g.flip().to_string(), "ba\ndc\nfe");
+        assert_eq!(g.rotate().rotate().rotate().rotate(), g);
+    }
+
+    #[test]
+    fn orientations_are_distinct() {
+        let orientations = grid("ab\ncd").orientations();
+        assert_eq!(orientations.len(), 8);
+        for (i, a) in orientations.iter().enumerate() {
+            assert!(orientations[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn addressing() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.step((0, 0), (-1, 0)), None);
+        assert_eq!(g.step((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(g.step_wrapping((0, 0), (-1, -1)), (2, 1));
+        assert_eq!(*g.get_wrapping(7, 3), 'e');
+        assert_eq!(g.neighbors4((0, 0)).count(), 2);
+        assert_eq!(g.neighbors8((1, 0)).count(), 5);
+        assert_eq!(
+            g.ray((0, 0), (1, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 0)]
+        );
+        assert_eq!(g.positions().nth(4), Some((1, 1)));
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let section = Section {
+            line: 4,
+            lines: vec!["..#", ".#"],
+        };
+        let err = Grid::parse(&section, Some).unwrap_err();
+        assert_eq!(err.line, Some(5));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::{AocError, Result};
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    /// The day's own `input/input.txt`.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `-` means stdin; anything else is a path.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// Names the input for reports: the resolved path, or `-` for stdin.
+    pub fn describe(&self, crate_dir: &Path) -> String {
+        match self {
+            Source::Default => default_path(crate_dir).display().to_string(),
+            Source::Path(path) => path.display().to_string(),
+            Source::Stdin => "-".to_owned(),
+        }
+    }
+
+    /// Reads the input, using `crate_dir` to locate the default file.
+    pub fn read(&self, crate_dir: &Path) -> Result<String> {
+        match self {
+            Source::Default => read_file(&default_path(crate_dir)),
+            Source::Path(path) => read_file(path),
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|err| AocError::io(format!("Failed to read stdin: {}", err)))?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+pub fn default_path(crate_dir: &Path) -> PathBuf {
+    crate_dir.join("input").join("input.txt")
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| {
+        let message = match err.kind() {
+            io::ErrorKind::NotFound => "Input file not found".to_owned(),
+            _ => format!("Failed to read input ({})", err),
+        };
+        AocError::io(message).in_file(path.display().to_string())
+    })
+}
+
+/// Looks for `--input <path>` among `args`, and `--trace` if `traceable`.
+fn parse_args<I>(mut args: I, traceable: bool) -> Result<(Source, bool)>
+where
+    I: Iterator<Item = String>,
+{
+    let mut source = Source::Default;
+    let mut trace = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => match args.next() {
+                Some(value) => source = Source::from_arg(&value),
+                None => return Err(AocError::usage("--input requires a value")),
+            },
+            "--trace" if traceable => trace = true,
+            _ => return Err(AocError::usage(format!("Unexpected argument: {}", arg))),
+        }
+    }
+    Ok((source, trace))
+}
+
+fn load_args(crate_dir: &str, traceable: bool) -> (String, String, bool) {
+    let crate_dir = Path::new(crate_dir);
+    parse_args(std::env::args().skip(1), traceable)
+        .and_then(|(source, trace)| {
+            Ok((source.describe(crate_dir), source.read(crate_dir)?, trace))
+        })
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            let trace = if traceable { " [--trace]" } else { "" };
+            eprintln!("\nUsage: cargo run [--input <path|->]{}", trace);
+            process::exit(1);
+        })
+}
+
+/// Reads the input for a day binary, honouring `--input` on the command line.
+///
+/// Pass `env!("CARGO_MANIFEST_DIR")` so the default input is found regardless
+/// of the working directory. Returns the input's name along with its content,
+/// and exits the process with a message on failure.
+pub fn load(crate_dir: &str) -> (String, String) {
+    let (name, content, _) = load_args(crate_dir, false);
+    (name, content)
+}
+
+/// Like `load`, for the days that can print the puzzle's walkthrough: also
+/// returns whether `--trace` was given.
+pub fn load_traced(crate_dir: &str) -> (String, String, bool) {
+    load_args(crate_dir, true)
+}
@@ -0,0 +1,12 @@
+// Lets code generated by `#[derive(FromLine)]` name this crate from inside it.
+extern crate self as common;
+
+pub mod automaton;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod line;
+pub mod modular;
+pub mod records;
+pub mod sets;
+pub mod solution;
@@ -0,0 +1,75 @@
+use crate::error::{AocError, Result};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Derives `FromStr` for a struct from a regex over the whole line, with a
+/// named group for each field:
+///
+/// ```
+/// use common::line::FromLine;
+///
+/// #[derive(FromLine)]
+/// #[pattern(r"mem\[(?P<address>\d+)\] = (?P<value>\d+)")]
+/// struct Write {
+///     address: u64,
+///     value: u64,
+/// }
+///
+/// let write: Write = "mem[8] = 11".parse().unwrap();
+/// assert_eq!((write.address, write.value), (8, 11));
+/// ```
+///
+/// Each field is parsed from its group with `FromStr`, and a field that
+/// doesn't parse is reported at its column. The struct also gets
+/// `from_line`, which returns `None` for a line that doesn't match at all,
+/// to try several patterns in turn.
+pub use common_derive::FromLine;
+
+#[doc(hidden)]
+pub use regex::Regex;
+
+/// Parses the group `name` of `captures`, which matched `line`, for
+/// `#[derive(FromLine)]`.
+#[doc(hidden)]
+pub fn field<T>(line: &str, captures: &regex::Captures<'_>, name: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let group = captures
+        .name(name)
+        .ok_or_else(|| AocError::parse(format!("missing {}", name)))?;
+    group.as_str().parse().map_err(|err| {
+        AocError::parse(format!("invalid {} `{}` ({})", name, group.as_str(), err))
+            .at_column(line[..group.start()].chars().count() + 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, FromLine)]
+    #[pattern(r"(?P<name>\w+) is (?P<age>\d+)")]
+    struct Person {
+        name: String,
+        age: u8,
+    }
+
+    #[test]
+    fn typed_fields() {
+        let person: Person = "ada is 36".parse().unwrap();
+        assert_eq!((person.name.as_str(), person.age), ("ada", 36));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Person::from_line("ada was 36").is_none());
+        assert!("ada is 36 ".parse::<Person>().is_err());
+        let err = "ada is 365".parse::<Person>().unwrap_err();
+        assert_eq!(err.column, Some(8));
+        // Columns count characters, not bytes.
+        let err = "zoë is 365".parse::<Person>().unwrap_err();
+        assert_eq!(err.column, Some(8));
+    }
+}
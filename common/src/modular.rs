@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+/// Finds `(g, x, y)` such that ax + by = g, where g = gcd(a, b) >= 0.
+///
+/// `None` means that one of them doesn't fit in an `i128`, which can only
+/// happen when `a` or `b` is close to `i128::MIN` or `i128::MAX`.
+pub fn ext_gcd(a: i128, b: i128) -> Option<(i128, i128, i128)> {
+    //
+    // This is "Euclid's Extended Algorithm". Alongside each remainder it
+    // keeps the coefficients that produce it from a and b, so when the
+    // remainder reaches the GCD we have x and y too.
+    //
+    let (mut r_last, mut r) = (a, b);
+    let (mut x_last, mut x): (i128, i128) = (1, 0);
+    let (mut y_last, mut y): (i128, i128) = (0, 1);
+    while r != 0 {
+        let q = r_last.checked_div(r)?;
+        (r_last, r) = (r, r_last.checked_sub(q.checked_mul(r)?)?);
+        (x_last, x) = (x, x_last.checked_sub(q.checked_mul(x)?)?);
+        (y_last, y) = (y, y_last.checked_sub(q.checked_mul(y)?)?);
+    }
+    if r_last < 0 {
+        Some((
+            r_last.checked_neg()?,
+            x_last.checked_neg()?,
+            y_last.checked_neg()?,
+        ))
+    } else {
+        Some((r_last, x_last, y_last))
+    }
+}
+
+/// Finds x such that ax =~ 1 (mod m), if `a` and `m` are coprime and `m` is
+/// positive.
+pub fn mod_inv(a: i128, m: i128) -> Option<i128> {
+    //
+    // If gcd(a, m) = 1 then ax + my = 1, so:
+    //      ax - 1 = (-y)m
+    //      m | ax - 1
+    //      ax =~ 1 (mod m)
+    //
+    if m <= 0 {
+        return None;
+    }
+    match ext_gcd(a.rem_euclid(m), m)? {
+        (1, x, _) => Some(x.rem_euclid(m)),
+        _ => None,
+    }
+}
+
+/// Computes base^exp (mod modulus) by repeated squaring, if `modulus` isn't 0.
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut exp = exp;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    Some(result as u64)
+}
+
+/// Finds the smallest number x that is `remainder` (mod `modulus`) for every
+/// one of `congruences`, along with the modulus the answer repeats with.
+///
+/// The moduli don't need to be coprime, but must be positive. `None` means
+/// that a modulus isn't, that no number satisfies every congruence, or that
+/// the working doesn't fit in an `i128`.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold((0, 1), |(a1, m1), &(a2, m2)| {
+        //
+        // x = a1 + m1 * k satisfies the congruences so far, for any k.
+        // For it to be a2 (mod m2) too, m1 * k =~ a2 - a1 (mod m2). That
+        // has a solution iff g = gcd(m1, m2) divides a2 - a1, and then
+        // k =~ (a2 - a1) / g * inv(m1 / g) (mod m2 / g).
+        //
+        if m2 <= 0 {
+            return None;
+        }
+        let (g, _, _) = ext_gcd(m1, m2)?;
+        let diff = a2.checked_sub(a1)?;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = m2 / g;
+        let k = (diff / g)
+            .rem_euclid(step)
+            .checked_mul(mod_inv(m1 / g, step)?)?
+            .rem_euclid(step);
+        let lcm = (m1 / g).checked_mul(m2)?;
+        let x = m1.checked_mul(k)?.checked_add(a1)?.rem_euclid(lcm);
+        Some((x, lcm))
+    })
+}
+
+/// Finds the smallest x such that base^x =~ target (mod modulus), if `base`
+/// and `modulus` are coprime and `modulus` isn't 0.
+///
+/// This is baby-step giant-step: with m = ceil(sqrt(modulus)), any x is
+/// i * m + j for some i and j below m. So remember base^j for every j, then
+/// step target * base^(-m * i) until it lands on one of them.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let m = (modulus as f64).sqrt().ceil() as u64;
+    let mut baby_steps = HashMap::new();
+    let mut value = 1 % modulus;
+    for j in 0..m {
+        baby_steps.entry(value).or_insert(j);
+        value = (value as u128 * base as u128 % modulus as u128) as u64;
+    }
+
+    let giant_step = mod_inv(mod_pow(base, m, modulus)? as i128, modulus as i128)? as u64;
+    let mut value = target % modulus;
+    for i in 0..m {
+        if let Some(&j) = baby_steps.get(&value) {
+            return Some(i * m + j);
+        }
+        value = (value as u128 * giant_step as u128 % modulus as u128) as u64;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd() {
+        assert_eq!(ext_gcd(240, 46), Some((2, -9, 47)));
+        assert_eq!(ext_gcd(-4, 6).unwrap().0, 2);
+        assert_eq!(ext_gcd(i128::MIN, -1), None);
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(mod_inv(3, 7), Some(5));
+        assert_eq!(mod_inv(10, 17), Some(12));
+        assert_eq!(mod_inv(-4, 7), Some(5));
+        assert_eq!(mod_inv(4, 6), None);
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -7), None);
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!(mod_pow(7, 8, 20201227), Some(5764801));
+        assert_eq!(mod_pow(2, 10, 1000), Some(24));
+        assert_eq!(mod_pow(5, 0, 1), Some(0));
+        assert_eq!(mod_pow(5, 2, 0), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Not coprime, but consistent.
+        assert_eq!(crt(&[(2, 4), (0, 6)]), Some((6, 12)));
+        assert_eq!(crt(&[(1, 4), (0, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0, 0)]), None);
+        assert_eq!(crt(&[(2, 3), (1, -5)]), None);
+        // a2 - a1 overflows.
+        assert_eq!(crt(&[(1, 2), (i128::MIN, 3)]), None);
+    }
+
+    #[test]
+    fn logarithm() {
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(3, 13, 17), Some(4));
+        assert_eq!(discrete_log(2, 3, 7), None);
+        assert_eq!(discrete_log(2, 3, 0), None);
+        assert_eq!(discrete_log(2, 5, 1), Some(0));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::error::{AocError, Result};
+use std::collections::HashMap;
+use std::io::{BufRead, Lines};
+
+/// Whether `line` separates records. Whitespace-only lines count, so that
+/// stray spaces or `\r`s don't merge two records.
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// A run of non-blank lines, borrowed from a `Record` or any other text.
+#[derive(Debug, PartialEq)]
+pub struct Section<'a> {
+    /// The 1-based line number of `lines[0]`, for error messages.
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    /// Parses each line with `f`, pointing any error at its line.
+    pub fn parse_lines<T, F>(&self, mut f: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&'a str) -> Result<T>,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(idx, &line)| f(line).map_err(|err| err.at_line(self.line + idx).with_text(line)))
+            .collect()
+    }
+
+    /// The first line (empty if there are no lines), and the lines after it.
+    pub fn split_header(&self) -> (&'a str, Section<'a>) {
+        let header = self.lines.first().copied().unwrap_or("");
+        let body = Section {
+            line: self.line + 1,
+            lines: self.lines.get(1..).unwrap_or_default().to_vec(),
+        };
+        (header, body)
+    }
+
+    /// The lines after the first, which must be `header`.
+    pub fn after_header(&self, header: &str) -> Result<Section<'a>> {
+        let (first, body) = self.split_header();
+        if first != header {
+            return Err(AocError::parse(format!("expected `{}`", header))
+                .at_line(self.line)
+                .with_text(first));
+        }
+        Ok(body)
+    }
+}
+
+/// A group of consecutive non-blank lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The 1-based line number of `lines[0]`, for error messages.
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Record {
+    /// The record's lines, ready for `Section::parse_lines`.
+    pub fn section(&self) -> Section<'_> {
+        Section {
+            line: self.line,
+            lines: self.lines.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Reads `key:value` fields separated by whitespace, on any of the
+    /// record's lines. A token without a `:`, or a key that appears twice,
+    /// is an error rather than something to guess about.
+    pub fn fields(&self) -> Result<HashMap<String, String>> {
+        let mut fields = HashMap::new();
+        self.section().parse_lines(|line| {
+            for field in line.split_whitespace() {
+                // `field` is a slice of `line`, so this is its byte offset.
+                let offset = field.as_ptr() as usize - line.as_ptr() as usize;
+                let column = line[..offset].chars().count() + 1;
+                let (key, value) = field
+                    .split_once(':')
+                    .ok_or_else(|| AocError::parse("expected `key:value`").at_column(column))?;
+                if fields.insert(key.to_owned(), value.to_owned()).is_some() {
+                    return Err(
+                        AocError::parse(format!("`{}` appears twice", key)).at_column(column)
+                    );
+                }
+            }
+            Ok(())
+        })?;
+        Ok(fields)
+    }
+}
+
+/// Reads blank-line-separated records one at a time, as puzzles with
+/// multi-line records need. Any number of blank lines may separate records,
+/// or come before or after them, and lines may end in `\n` or `\r\n`.
+pub struct Records<R> {
+    lines: Lines<R>,
+    /// How many lines have been read so far.
+    line: usize,
+}
+
+pub fn records<R: BufRead>(reader: R) -> Records<R> {
+    Records {
+        lines: reader.lines(),
+        line: 0,
+    }
+}
+
+/// Reads every record of `content`, which must have exactly one for each of
+/// `names`. The names describe the records in the error message.
+pub fn read_exactly(content: &str, names: &[&str]) -> Result<Vec<Record>> {
+    let records = records(content.as_bytes()).collect::<Result<Vec<Record>>>()?;
+    if records.len() != names.len() {
+        let described = match names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => names.join(""),
+        };
+        return Err(AocError::parse(format!(
+            "expected {} sections ({}), found {}",
+            names.len(),
+            described,
+            records.len()
+        )));
+    }
+    Ok(records)
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Result<Record>> {
+        let mut record: Option<Record> = None;
+        loop {
+            let line = match self.lines.next() {
+                None => return record.map(Ok),
+                Some(Err(err)) => {
+                    return Some(Err(AocError::io(format!("Failed to read input ({})", err))
+                        .at_line(self.line + 1)))
+                }
+                Some(Ok(line)) => line,
+            };
+            self.line += 1;
+            if is_blank(&line) {
+                if record.is_some() {
+                    return record.map(Ok);
+                }
+            } else {
+                let line_number = self.line;
+                record
+                    .get_or_insert_with(|| Record {
+                        line: line_number,
+                        lines: Vec::new(),
+                    })
+                    .lines
+                    .push(line);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(content: &str) -> Vec<Record> {
+        records(content.as_bytes())
+            .collect::<Result<Vec<Record>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn blank_lines_and_line_endings() {
+        let expected = vec![
+            Record {
+                line: 2,
+                lines: vec!["a".to_owned(), "b".to_owned()],
+            },
+            Record {
+                line: 6,
+                lines: vec!["c".to_owned()],
+            },
+        ];
+        assert_eq!(read("\na\nb\n\n\nc\n\n"), expected);
+        assert_eq!(read("\r\na\r\nb\r\n \r\n\r\nc"), expected);
+    }
+
+    #[test]
+    fn headers_and_counts() {
+        let records = read_exactly("Rules:\na\n\nb\n", &["rules", "messages"]).unwrap();
+        let body = records[0].section().after_header("Rules:").unwrap();
+        assert_eq!(
+            body,
+            Section {
+                line: 2,
+                lines: vec!["a"]
+            }
+        );
+        let err = records[1].section().after_header("Messages:").unwrap_err();
+        assert_eq!((err.line, err.text.as_deref()), (Some(4), Some("b")));
+
+        let err = read_exactly("a\n", &["one", "two", "three"]).unwrap_err();
+        assert_eq!(
+            err.message,
+            "expected 3 sections (one, two and three), found 1"
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let record = &read("a:1 b:2\nc:3")[0];
+        let fields = record.fields().unwrap();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields["c"], "3");
+
+        let record = &read("\na:1\nb:2  c a:3")[0];
+        let err = record.fields().unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(6)));
+        let record = &read("a:1 b:2\na:3")[0];
+        let err = record.fields().unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(1)));
+        // Columns count characters, not bytes.
+        let record = &read("é:1 b:2 c")[0];
+        assert_eq!(record.fields().unwrap_err().column, Some(9));
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeMap, HashSet};
+use std::hash::Hash;
+
+/// The items that are in every one of `sets`; empty if there are none.
+pub fn intersection<'a, T, I>(sets: I) -> HashSet<T>
+where
+    T: Eq + Hash + Clone + 'a,
+    I: IntoIterator<Item = &'a HashSet<T>>,
+{
+    let mut sets = sets.into_iter();
+    let first = match sets.next() {
+        Some(set) => set.clone(),
+        None => return HashSet::new(),
+    };
+    sets.fold(first, |common, set| {
+        common
+            .into_iter()
+            .filter(|item| set.contains(item))
+            .collect()
+    })
+}
+
+/// Settles each key with a single candidate, and rules that candidate out for
+/// every other key, until nothing changes. Fails with the first key left
+/// unsettled.
+pub fn eliminate<K, T>(mut candidates: BTreeMap<K, HashSet<T>>) -> Result<BTreeMap<K, T>, K>
+where
+    K: Ord + Clone,
+    T: Eq + Hash + Clone,
+{
+    let mut settled = BTreeMap::new();
+    while let Some((key, items)) = candidates.iter().find(|(_, items)| items.len() == 1) {
+        let key = key.clone();
+        let item = items.iter().next().unwrap().clone();
+        candidates.remove(&key);
+        for items in candidates.values_mut() {
+            items.remove(&item);
+        }
+        settled.insert(key, item);
+    }
+    match candidates.into_iter().next() {
+        None => Ok(settled),
+        Some((key, _)) => Err(key),
+    }
+}
+
+/// The items that are in any of `sets`.
+pub fn union<'a, T, I>(sets: I) -> HashSet<T>
+where
+    T: Eq + Hash + Clone + 'a,
+    I: IntoIterator<Item = &'a HashSet<T>>,
+{
+    sets.into_iter().flatten().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_and_union() {
+        let sets: Vec<HashSet<char>> = vec!["abc", "bcd", "cb"]
+            .into_iter()
+            .map(|s| s.chars().collect())
+            .collect();
+        assert_eq!(intersection(&sets), "bc".chars().collect());
+        assert_eq!(union(&sets), "abcd".chars().collect());
+        assert!(intersection::<char, _>(&[]).is_empty());
+    }
+
+    #[test]
+    fn elimination() {
+        let set = |s: &str| s.chars().collect::<HashSet<char>>();
+        let candidates: BTreeMap<u8, HashSet<char>> =
+            vec![(1, set("ab")), (2, set("b")), (3, set("abc"))]
+                .into_iter()
+                .collect();
+        let expected: BTreeMap<u8, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        assert_eq!(eliminate(candidates), Ok(expected));
+
+        let candidates: BTreeMap<u8, HashSet<char>> =
+            vec![(1, set("ab")), (2, set("ab"))].into_iter().collect();
+        assert_eq!(eliminate(candidates), Err(1));
+    }
+}
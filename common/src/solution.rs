@@ -0,0 +1,150 @@
+use crate::error::AocError;
+use std::any::Any;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+///
+/// Parse errors should point at the offending line; `solve` fills in the file.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// The parts the puzzle has. Day 25 only has a part 1, so it leaves
+    /// `Part::Two` out and `solve` never asks for it.
+    const PARTS: &'static [Part] = Part::BOTH;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError>;
+}
+
+#[derive(Debug, Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: &'static [Part] = &[Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {}", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A rendered answer, or the error that prevented it.
+pub type Outcome = Result<String, AocError>;
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Everything `solve` found out about one input.
+#[derive(Debug)]
+pub struct Report {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// `solve` for a particular `Solution`, with the day's types erased.
+pub type Solver = fn(&str, &str, &[Part]) -> Report;
+
+/// Renders the step-by-step walkthrough the puzzle shows for an input.
+pub type Tracer = fn(&str) -> Result<String, AocError>;
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_owned()
+    }
+}
+
+fn catch<F: FnOnce() -> Result<T, AocError>, T>(f: F) -> Result<T, AocError> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(AocError::panic(panic_message(payload))))
+}
+
+fn timed<F: FnOnce() -> Result<T, AocError>, T>(f: F) -> (Result<T, AocError>, Duration) {
+    let start = Instant::now();
+    let result = catch(f);
+    (result, start.elapsed())
+}
+
+/// Parses `content` once and solves each of `parts` that the puzzle has,
+/// timing every step.
+///
+/// A failing solver only fails its own part (or every part, if parsing
+/// fails), so one unfinished day can't take down a whole run. Panics are
+/// caught too, as a last resort. Errors are attributed to the input `name`.
+pub fn solve<S: Solution>(name: &str, content: &str, parts: &[Part]) -> Report {
+    let (input, parse_elapsed) = timed(|| S::parse(content));
+    let answers = parts
+        .iter()
+        .filter(|part| S::PARTS.contains(part))
+        .map(|&part| {
+            let (outcome, elapsed) = match &input {
+                Err(err) => (Err(err.clone()), Duration::default()),
+                Ok(input) => match part {
+                    Part::One => timed(|| S::part1(input).map(|answer| answer.to_string())),
+                    Part::Two => timed(|| S::part2(input).map(|answer| answer.to_string())),
+                },
+            };
+            let outcome = outcome.map_err(|err| err.in_file(name));
+            Answer {
+                part,
+                outcome,
+                elapsed,
+            }
+        })
+        .collect();
+    Report {
+        parse_elapsed,
+        answers,
+    }
+}
+
+/// Solves both parts and prints the answers, as the day binaries do.
+pub fn print<S: Solution>(name: &str, content: &str) {
+    for answer in solve::<S>(name, content, S::PARTS).answers {
+        match answer.outcome {
+            Ok(value) => println!("Part {}: {}", answer.part, value),
+            Err(err) => eprintln!("Part {} failed: {}", answer.part, err),
+        }
+    }
+}
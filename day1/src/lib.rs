@@ -0,0 +1,77 @@
+use common::error::{parse_lines, parse_number, AocError};
+use common::solution::Solution;
+use std::collections::HashMap;
+
+pub fn find_n_numbers_summing_to_target(
+    num_counts: &mut HashMap<u32, u8>,
+    n: u8,
+    target: u32,
+) -> Option<Vec<u32>> {
+    if n > 0 {
+        for (x, count) in num_counts.clone() {
+            if x > target || count == 0 {
+                continue;
+            }
+            *num_counts.get_mut(&x).unwrap() -= 1;
+            if let Some(mut summands) =
+                find_n_numbers_summing_to_target(num_counts, n - 1, target - x)
+            {
+                summands.push(x);
+                return Some(summands);
+            }
+            *num_counts.get_mut(&x).unwrap() += 1;
+        }
+    } else if target == 0 {
+        return Some(Vec::new());
+    }
+
+    None
+}
+
+fn product_of_summands(num_counts: &HashMap<u32, u8>, n: u8, target: u32) -> Result<u32, AocError> {
+    let summands = find_n_numbers_summing_to_target(&mut num_counts.clone(), n, target)
+        .ok_or_else(|| {
+            AocError::no_solution(format!(
+                "Couldn't find {} distinct numbers summing to {}",
+                n, target
+            ))
+        })?;
+    Ok(summands.iter().product())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = HashMap<u32, u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let mut num_counts: HashMap<u32, u8> = HashMap::new();
+        for num in parse_lines(content, |l| parse_number(l, 1))? {
+            let counter = num_counts.entry(num).or_insert(0);
+            *counter += 1;
+        }
+        Ok(num_counts)
+    }
+
+    fn part1(num_counts: &Self::Input) -> Result<u32, AocError> {
+        product_of_summands(num_counts, 2, 2020)
+    }
+
+    fn part2(num_counts: &Self::Input) -> Result<u32, AocError> {
+        product_of_summands(num_counts, 3, 2020)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day1::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day1::part1(&input).unwrap(), 514579);
+        assert_eq!(Day1::part2(&input).unwrap(), 241861950);
+    }
+}
@@ -0,0 +1,77 @@
+use common::error::{parse_lines, parse_number, AocError};
+use common::solution::Solution;
+use std::collections::HashMap;
+
+fn count_diffs(adapters: &[u64]) -> u64 {
+    let mut diffs: HashMap<u64, u64> = HashMap::new();
+    let mut last = 0;
+    for adapter in adapters {
+        let diff = adapter - last;
+        let entry = diffs.entry(diff).or_insert(0);
+        *entry += 1;
+        last = *adapter;
+    }
+    // 1-jolt diffs multiplied by 3-jolt diffs
+    diffs.get(&1).unwrap_or(&0) * diffs.get(&3).unwrap_or(&0)
+}
+
+fn count_arrangements(adapters: &[u64]) -> u64 {
+    let mut num_arrangements_from_index: Vec<u64> = vec![0; adapters.len()];
+    // There is just one way to arrange the final adapter.
+    num_arrangements_from_index[adapters.len() - 1] = 1;
+
+    for (i, adapter) in adapters.iter().enumerate().rev().skip(1) {
+        // Look back in the `adapters` vec for adapters <= adapter + 3.
+        let mut j = i + 1;
+        while j < adapters.len() && adapters[j] <= adapter + 3 {
+            num_arrangements_from_index[i] += num_arrangements_from_index[j];
+            j += 1;
+        }
+    }
+
+    num_arrangements_from_index[0]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let mut adapters: Vec<u64> = parse_lines(content, |l| parse_number(l, 1))?;
+        adapters.push(0);
+        adapters.sort_unstable();
+        // Your device's built-in adapter is always 3 higher than the highest adapter
+        adapters.push(adapters.last().unwrap() + 3);
+        Ok(adapters)
+    }
+
+    fn part1(adapters: &Self::Input) -> Result<u64, AocError> {
+        Ok(count_diffs(adapters))
+    }
+
+    fn part2(adapters: &Self::Input) -> Result<u64, AocError> {
+        Ok(count_arrangements(adapters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_example() {
+        let input = Day10::parse(include_str!("../input/input2.txt")).unwrap();
+        assert_eq!(Day10::part1(&input).unwrap(), 35);
+        assert_eq!(Day10::part2(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn large_example() {
+        let input = Day10::parse(include_str!("../input/input3.txt")).unwrap();
+        assert_eq!(Day10::part1(&input).unwrap(), 220);
+        assert_eq!(Day10::part2(&input).unwrap(), 19208);
+    }
+}
@@ -0,0 +1,98 @@
+use common::automaton::Automaton;
+use common::error::AocError;
+use common::grid::{Grid, Position, ALL_DIRECTIONS};
+use common::solution::Solution;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+type Layout = Grid<Seat>;
+
+// The floor never changes, so these leave it out: a seat's neighbours are
+// only seats, and the floor has none.
+
+fn adjacent_seats(grid: &Layout, pos: Position) -> Vec<Position> {
+    if grid[pos] == Seat::Floor {
+        return Vec::new();
+    }
+    grid.neighbors8(pos)
+        .filter(|&pos| grid[pos] != Seat::Floor)
+        .collect()
+}
+
+/// The first seat in each direction, looking past the floor.
+fn visible_seats(grid: &Layout, pos: Position) -> Vec<Position> {
+    if grid[pos] == Seat::Floor {
+        return Vec::new();
+    }
+    ALL_DIRECTIONS
+        .iter()
+        .filter_map(|&dir| grid.ray(pos, dir).find(|&pos| grid[pos] != Seat::Floor))
+        .collect()
+}
+
+fn count_occupied_seats(grid: &Layout) -> u32 {
+    grid.cells().filter(|&&seat| seat == Seat::Occupied).count() as u32
+}
+
+/// Lets people sit down and get up until nobody moves, returning how many
+/// seats end up occupied. People leave once `cutoff` of the seats they
+/// consider are occupied.
+fn apply_until_complete(
+    grid: &Layout,
+    neighbors: fn(&Layout, Position) -> Vec<Position>,
+    cutoff: usize,
+) -> u32 {
+    let rule = |seat, neighbors: &[Seat]| {
+        let occupied = neighbors.iter().filter(|&&n| n == Seat::Occupied).count();
+        match seat {
+            Seat::Empty if occupied == 0 => Seat::Occupied,
+            Seat::Occupied if occupied >= cutoff => Seat::Empty,
+            seat => seat,
+        }
+    };
+    let mut automaton = Automaton::new(grid.clone(), neighbors, rule);
+    automaton.run_until_stable();
+    count_occupied_seats(&automaton.space)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Layout;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        Grid::parse_str(content, |ch| match ch {
+            '.' => Some(Seat::Floor),
+            'L' => Some(Seat::Empty),
+            '#' => Some(Seat::Occupied),
+            _ => None,
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Result<u32, AocError> {
+        Ok(apply_until_complete(grid, adjacent_seats, 4))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<u32, AocError> {
+        Ok(apply_until_complete(grid, visible_seats, 5))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day11::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day11::part1(&input).unwrap(), 37);
+        assert_eq!(Day11::part2(&input).unwrap(), 26);
+    }
+}
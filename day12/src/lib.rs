@@ -0,0 +1,150 @@
+use common::error::{parse_lines, parse_number, AocError};
+use common::solution::Solution;
+use std::str::FromStr;
+
+/// A position relative to the ship, or a direction the ship faces.
+#[derive(Debug)]
+struct Waypoint {
+    north: i32,
+    east: i32,
+}
+
+impl Waypoint {
+    /// Turns clockwise about the ship by `quarters` right angles.
+    fn rotate(&mut self, quarters: u8) {
+        for _ in 0..quarters {
+            let north = self.north;
+            self.north = -self.east;
+            self.east = north;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Ship {
+    north: i32,
+    east: i32,
+    heading: Waypoint,
+    waypoint: Waypoint,
+}
+
+impl Ship {
+    fn new() -> Ship {
+        Ship {
+            north: 0,
+            east: 0,
+            heading: Waypoint { north: 0, east: 1 },
+            waypoint: Waypoint { north: 1, east: 10 },
+        }
+    }
+
+    fn apply_action(&mut self, action: &Action) {
+        match action {
+            Action::MoveY(amount) => self.north += amount,
+            Action::MoveX(amount) => self.east += amount,
+            Action::Turn(quarters) => self.heading.rotate(*quarters),
+            Action::MoveForward(amount) => {
+                self.north += amount * self.heading.north;
+                self.east += amount * self.heading.east;
+            }
+        }
+    }
+
+    fn apply_action_waypoint(&mut self, action: &Action) {
+        match action {
+            Action::MoveY(amount) => self.waypoint.north += amount,
+            Action::MoveX(amount) => self.waypoint.east += amount,
+            Action::Turn(quarters) => self.waypoint.rotate(*quarters),
+            Action::MoveForward(amount) => {
+                self.north += amount * self.waypoint.north;
+                self.east += amount * self.waypoint.east;
+            }
+        }
+    }
+
+    fn manhattan_distance(&self) -> i32 {
+        self.north.abs() + self.east.abs()
+    }
+}
+
+#[derive(Debug)]
+pub enum Action {
+    MoveY(i32),
+    MoveX(i32),
+    /// Clockwise quarter turns, from 0 to 3.
+    Turn(u8),
+    MoveForward(i32),
+}
+
+impl FromStr for Action {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let code = chars
+            .next()
+            .ok_or_else(|| AocError::parse("expected an action"))?;
+        let value = &parse_number::<i32>(chars.as_str(), 2)?;
+        if matches!(code, 'L' | 'R') && value % 90 != 0 {
+            return Err(AocError::parse("turns must be a multiple of 90 degrees").at_column(2));
+        }
+        match code {
+            'N' => Ok(Action::MoveY(*value)),
+            'S' => Ok(Action::MoveY(*value * -1)),
+            'E' => Ok(Action::MoveX(*value)),
+            'W' => Ok(Action::MoveX(*value * -1)),
+            'L' => Ok(Action::Turn((-*value / 90).rem_euclid(4) as u8)),
+            'R' => Ok(Action::Turn((*value / 90).rem_euclid(4) as u8)),
+            'F' => Ok(Action::MoveForward(*value)),
+            _ => Err(AocError::parse("expected one of `NSEWLRF`").at_column(1)),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Action>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, |line| line.parse::<Action>())
+    }
+
+    fn part1(actions: &Self::Input) -> Result<i32, AocError> {
+        let mut ship = Ship::new();
+        for action in actions {
+            ship.apply_action(action);
+        }
+        Ok(ship.manhattan_distance())
+    }
+
+    fn part2(actions: &Self::Input) -> Result<i32, AocError> {
+        let mut ship = Ship::new();
+        for action in actions {
+            ship.apply_action_waypoint(action);
+        }
+        Ok(ship.manhattan_distance())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day12::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 25);
+        assert_eq!(Day12::part2(&input).unwrap(), 286);
+    }
+
+    #[test]
+    fn negative_turns() {
+        let input = Day12::parse("F10\nR-90\nF5\n").unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 15);
+        let input = Day12::parse("F10\nL450\nF5\n").unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 15);
+    }
+}
@@ -0,0 +1,114 @@
+use common::error::{parse_list, parse_number, AocError};
+use common::modular::crt;
+use common::solution::Solution;
+use std::convert::TryFrom;
+
+pub struct Notes {
+    // Not every set of notes has a usable timestamp; part 2 ignores it,
+    // so a bad one is only reported from part 1.
+    start_time: Result<u32, AocError>,
+    // `None` for buses that are out of service ("x").
+    bus_ids: Vec<Option<u32>>,
+}
+
+fn earliest_bus(notes: &Notes) -> Result<u32, AocError> {
+    let start_time = notes.start_time.clone()?;
+    let (bus_id, min_wait_time) = notes
+        .bus_ids
+        .iter()
+        .flatten()
+        .map(|&bus_id| (bus_id, (start_time / bus_id + 1) * bus_id - start_time))
+        .min_by(|(_, min_wait_time), (_, curr_wait_time)| min_wait_time.cmp(curr_wait_time))
+        .ok_or_else(|| AocError::no_solution("There are no buses in service"))?;
+    Ok(min_wait_time * bus_id)
+}
+
+/// The earliest time at which each bus leaves as many minutes after it as
+/// its position in the list.
+fn earliest_sequential_departure(notes: &Notes) -> Result<i64, AocError> {
+    //
+    // If bus `a` leaves at x + k, then x + k =~ 0 (mod a), so x =~ -k (mod a).
+    // The Chinese remainder theorem finds the x satisfying all of those.
+    //
+    let congruences: Vec<(i128, i128)> = notes
+        .bus_ids
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, bus_id)| bus_id.map(|bus_id| (bus_id as i128, idx as i128)))
+        .map(|(bus_id, idx)| ((-idx).rem_euclid(bus_id), bus_id))
+        .collect();
+    let (time, _) = crt(&congruences)
+        .ok_or_else(|| AocError::no_solution("No time lines up with every bus"))?;
+    i64::try_from(time).map_err(|_| AocError::no_solution(format!("{} overflows", time)))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+    type Answer1 = u32;
+    type Answer2 = i64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let mut lines = content.lines();
+        let start_time = lines
+            .next()
+            .ok_or_else(|| AocError::parse("missing start time").at_line(1))
+            .and_then(|line| {
+                parse_number::<u32>(line, 1).map_err(|err| err.at_line(1).with_text(line))
+            });
+        let line = lines
+            .next()
+            .ok_or_else(|| AocError::parse("missing bus ids").at_line(2))?;
+        let bus_ids = parse_list(line, ",", |bus_id| match bus_id {
+            "x" => Ok(None),
+            _ => match parse_number::<u32>(bus_id, 1)? {
+                0 => Err(AocError::parse("bus ids must be positive")),
+                bus_id => Ok(Some(bus_id)),
+            },
+        })
+        .map_err(|err| err.at_line(2).with_text(line))?;
+        Ok(Notes {
+            start_time,
+            bus_ids,
+        })
+    }
+
+    fn part1(notes: &Self::Input) -> Result<u32, AocError> {
+        earliest_bus(notes)
+    }
+
+    fn part2(notes: &Self::Input) -> Result<i64, AocError> {
+        earliest_sequential_departure(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day13::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day13::part1(&input).unwrap(), 295);
+        assert_eq!(Day13::part2(&input).unwrap(), 1068781);
+    }
+
+    #[test]
+    fn short_schedule() {
+        let input = Day13::parse(include_str!("../input/input2.txt")).unwrap();
+        assert_eq!(Day13::part2(&input).unwrap(), 3417);
+        // The file has no start time, which only part 1 needs.
+        let err = Day13::part1(&input).unwrap_err();
+        assert_eq!(err.line, Some(1));
+        assert_eq!(err.text.as_deref(), Some("hmm"));
+    }
+
+    #[test]
+    fn shared_factors() {
+        let input = Day13::parse("0\n2,x,4").unwrap();
+        assert_eq!(Day13::part2(&input).unwrap(), 2);
+        let input = Day13::parse("0\n2,4").unwrap();
+        assert!(Day13::part2(&input).is_err());
+    }
+}
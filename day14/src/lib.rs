@@ -0,0 +1,129 @@
+use common::error::{parse_lines, AocError};
+use common::line::FromLine;
+use common::solution::Solution;
+use std::collections::HashMap;
+
+#[derive(FromLine)]
+#[pattern(r"mask\s=\s(?P<mask>[X01]+)")]
+struct MaskLine {
+    mask: String,
+}
+
+#[derive(FromLine)]
+#[pattern(r"mem\[(?P<address>\d+)\]\s=\s(?P<value>\d+)")]
+struct MemLine {
+    address: u64,
+    value: u64,
+}
+
+pub enum Instruction {
+    Mask(String),
+    Mem(u64, u64),
+}
+
+/// Masks are 36 bits wide, with the most significant bit first.
+const MASK_BITS: usize = 36;
+
+fn parse_line(line: &str) -> Result<Instruction, AocError> {
+    if let Some(MaskLine { mask }) = MaskLine::from_line(line).transpose()? {
+        if mask.len() != MASK_BITS {
+            return Err(AocError::parse(format!("masks must be {} bits", MASK_BITS))
+                .at_column("mask = ".len() + 1));
+        }
+        Ok(Instruction::Mask(mask))
+    } else if let Some(MemLine { address, value }) = MemLine::from_line(line).transpose()? {
+        Ok(Instruction::Mem(address, value))
+    } else {
+        Err(AocError::parse(
+            "expected `mask = <bits>` or `mem[<address>] = <value>`",
+        ))
+    }
+}
+
+fn run_v1(program: &[Instruction]) -> u64 {
+    let mut mem: HashMap<u64, u64> = HashMap::new();
+    let (mut and_mask, mut or_mask) = (0, 0);
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(mask) => {
+                and_mask = u64::from_str_radix(mask.replace('X', "1").as_str(), 2).unwrap();
+                or_mask = u64::from_str_radix(mask.replace('X', "0").as_str(), 2).unwrap();
+            }
+            Instruction::Mem(address, value) => {
+                mem.insert(*address, and_mask & (or_mask | value));
+            }
+        }
+    }
+    mem.values().sum()
+}
+
+fn gen_v2_addresses(address: u64, mask: &str, i: usize) -> Vec<u64> {
+    match mask
+        .chars()
+        .nth(mask.len().checked_sub(i + 1).unwrap_or(mask.len()))
+    {
+        None => vec![0],
+        Some(curr) => gen_v2_addresses(address, mask, i + 1)
+            .iter()
+            .flat_map(|&n| {
+                if curr == 'X' {
+                    vec![n, n | 1 << i]
+                } else if curr == '1' {
+                    vec![n | 1 << i]
+                } else {
+                    vec![n | address & 1 << i]
+                }
+            })
+            .collect(),
+    }
+}
+
+fn run_v2(program: &[Instruction]) -> u64 {
+    let mut mem: HashMap<u64, u64> = HashMap::new();
+    let mut mask = "";
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(m) => mask = m,
+            Instruction::Mem(address, value) => {
+                for addr in gen_v2_addresses(*address, mask, 0) {
+                    mem.insert(addr, *value);
+                }
+            }
+        }
+    }
+    mem.values().sum()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, parse_line)
+    }
+
+    fn part1(program: &Self::Input) -> Result<u64, AocError> {
+        Ok(run_v1(program))
+    }
+
+    fn part2(program: &Self::Input) -> Result<u64, AocError> {
+        Ok(run_v2(program))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day14::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day14::part1(&input).unwrap(), 165);
+        // The part 1 example has too many floating bits for part 2.
+        let input = Day14::parse(include_str!("../input/input2.txt")).unwrap();
+        assert_eq!(Day14::part2(&input).unwrap(), 208);
+    }
+}
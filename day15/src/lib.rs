@@ -0,0 +1,74 @@
+use common::error::{parse_list, parse_number, AocError};
+use common::solution::Solution;
+use std::collections::HashMap;
+
+/// How long each part's game lasts.
+pub const PART1_TURNS: usize = 2020;
+pub const PART2_TURNS: usize = 30_000_000;
+
+/// The number spoken on turn `turns`, counting the starting numbers.
+pub fn play(input: &[usize], turns: usize) -> usize {
+    assert!(turns > 0, "turns are counted from 1");
+    if turns <= input.len() {
+        return input[turns - 1];
+    }
+    let mut positions: HashMap<usize, usize> = input
+        .iter()
+        .take(input.len() - 1)
+        .enumerate()
+        .map(|(idx, num)| (*num, idx))
+        .collect();
+    let mut last_num = *input.last().unwrap();
+    let mut i: usize = input.len() - 1;
+    while i < turns - 1 {
+        let pos = match positions.get(&last_num) {
+            Some(last_pos) => i - last_pos,
+            None => 0,
+        };
+        positions.insert(last_num, i);
+        last_num = pos;
+        i += 1;
+    }
+    last_num
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let line = content.trim_end();
+        parse_list(line, ",", |num| parse_number(num, 1))
+            .map_err(|err| err.at_line(1).with_text(line))
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, AocError> {
+        Ok(play(input, PART1_TURNS))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, AocError> {
+        Ok(play(input, PART2_TURNS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day15::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day15::part1(&input).unwrap(), 436);
+        assert_eq!(Day15::part2(&input).unwrap(), 175594);
+    }
+
+    #[test]
+    fn short_games() {
+        let input = Day15::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(play(&input, 2), 3);
+        assert_eq!(play(&input, 10), 0);
+    }
+}
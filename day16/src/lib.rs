@@ -0,0 +1,172 @@
+use common::error::{parse_list, parse_number, AocError};
+use common::records;
+use common::records::Section;
+use common::sets;
+use common::solution::Solution;
+use std::collections::{BTreeMap, HashSet};
+
+pub struct Rule {
+    name: String,
+    ranges: Vec<(u32, u32)>,
+}
+
+impl Rule {
+    fn matches(&self, value: u32) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(low, high)| low <= value && value <= high)
+    }
+}
+
+pub struct Notes {
+    rules: Vec<Rule>,
+    ticket: Vec<u32>,
+    nearby: Vec<Vec<u32>>,
+}
+
+fn parse_rule(line: &str) -> Result<Rule, AocError> {
+    let malformed = || AocError::parse("expected `<field>: <a>-<b> or <c>-<d>`");
+    let (name, ranges) = line.split_once(": ").ok_or_else(malformed)?;
+    let ranges = parse_list(ranges, " or ", |range| {
+        let (low, high) = range.split_once('-').ok_or_else(malformed)?;
+        Ok((
+            parse_number(low, 1)?,
+            parse_number(high, low.chars().count() + 2)?,
+        ))
+    })
+    .map_err(|err| err.offset_column(name.chars().count() + ": ".len()))?;
+    Ok(Rule {
+        name: name.to_owned(),
+        ranges,
+    })
+}
+
+fn parse_ticket(line: &str, num_fields: usize) -> Result<Vec<u32>, AocError> {
+    let ticket = parse_list(line, ",", |value| parse_number(value, 1))?;
+    if ticket.len() != num_fields {
+        return Err(AocError::parse(format!(
+            "expected {} values, found {}",
+            num_fields,
+            ticket.len()
+        )));
+    }
+    Ok(ticket)
+}
+
+/// Parses a section of tickets, under its `header` line.
+fn parse_tickets(
+    section: &Section<'_>,
+    header: &str,
+    num_fields: usize,
+) -> Result<Vec<Vec<u32>>, AocError> {
+    section
+        .after_header(header)?
+        .parse_lines(|line| parse_ticket(line, num_fields))
+}
+
+fn is_valid(value: u32, rules: &[Rule]) -> bool {
+    rules.iter().any(|rule| rule.matches(value))
+}
+
+fn error_rate(notes: &Notes) -> u32 {
+    notes
+        .nearby
+        .iter()
+        .flatten()
+        .filter(|&&value| !is_valid(value, &notes.rules))
+        .sum()
+}
+
+/// Works out which rule applies to each position on the tickets.
+fn assign_fields(notes: &Notes) -> Result<Vec<usize>, AocError> {
+    let num_fields = notes.rules.len();
+    let valid: Vec<&Vec<u32>> = notes
+        .nearby
+        .iter()
+        .filter(|ticket| ticket.iter().all(|&value| is_valid(value, &notes.rules)))
+        .collect();
+
+    // The rules that every valid ticket satisfies at each position.
+    let candidates: BTreeMap<usize, HashSet<usize>> = (0..num_fields)
+        .map(|pos| {
+            (0..num_fields)
+                .filter(|&rule| {
+                    valid
+                        .iter()
+                        .all(|ticket| notes.rules[rule].matches(ticket[pos]))
+                })
+                .collect()
+        })
+        .enumerate()
+        .collect();
+
+    sets::eliminate(candidates)
+        .map(|fields| fields.into_values().collect())
+        .map_err(|pos| {
+            AocError::no_solution(format!(
+                "Couldn't deduce which field is at position {}",
+                pos + 1
+            ))
+        })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let records = records::read_exactly(content, &["rules", "your ticket", "nearby tickets"])?;
+        let rules = records[0].section().parse_lines(parse_rule)?;
+        let mut ticket = parse_tickets(&records[1].section(), "your ticket:", rules.len())?;
+        if ticket.len() != 1 {
+            return Err(
+                AocError::parse("expected exactly one ticket of your own").at_line(records[1].line)
+            );
+        }
+        let nearby = parse_tickets(&records[2].section(), "nearby tickets:", rules.len())?;
+        Ok(Notes {
+            rules,
+            ticket: ticket.remove(0),
+            nearby,
+        })
+    }
+
+    fn part1(notes: &Self::Input) -> Result<u32, AocError> {
+        Ok(error_rate(notes))
+    }
+
+    fn part2(notes: &Self::Input) -> Result<u64, AocError> {
+        Ok(assign_fields(notes)?
+            .iter()
+            .zip(&notes.ticket)
+            .filter(|(&rule, _)| notes.rules[rule].name.starts_with("departure"))
+            .map(|(_, &value)| value as u64)
+            .product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day16::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day16::part1(&input).unwrap(), 71);
+    }
+
+    #[test]
+    fn field_positions() {
+        // The part 2 example has no departure fields, so check the deduction itself.
+        let input = Day16::parse(include_str!("../input/input2.txt")).unwrap();
+        let names: Vec<&str> = assign_fields(&input)
+            .unwrap()
+            .iter()
+            .map(|&rule| input.rules[rule].name.as_str())
+            .collect();
+        assert_eq!(names, vec!["row", "class", "seat"]);
+    }
+}
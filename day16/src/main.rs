@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day16::Day16;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day16>(&name, &content);
+}
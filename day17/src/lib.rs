@@ -0,0 +1,205 @@
+use common::automaton::{Automaton, Space};
+use common::error::AocError;
+use common::grid::Grid;
+use common::solution::Solution;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+type Position = (i32, i32, i32, i32);
+
+/// How many dimensions the pocket universe has. A 3D universe keeps `w` at 0.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Dimensions {
+    Three,
+    Four,
+}
+
+#[derive(Debug, Clone)]
+pub struct Universe {
+    cubes: HashMap<Position, bool>,
+    planar_width: u32,
+    depth: u32,
+    dimensions: Dimensions,
+}
+
+impl Universe {
+    fn with_dimensions(&self, dimensions: Dimensions) -> Universe {
+        Universe {
+            dimensions,
+            ..self.clone()
+        }
+    }
+
+    /// The values of `w` within `radius` of 0 that this universe has.
+    fn w_range(&self, radius: i32) -> RangeInclusive<i32> {
+        match self.dimensions {
+            Dimensions::Three => 0..=0,
+            Dimensions::Four => -radius..=radius,
+        }
+    }
+
+    fn neighbors(&self, (x, y, z, w): Position) -> Vec<Position> {
+        let mut neighbors = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    for dw in self.w_range(1) {
+                        if dx == 0 && dy == 0 && dz == 0 && dw == 0 {
+                            continue;
+                        }
+                        neighbors.push((x + dx, y + dy, z + dz, w + dw));
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn get_num_active(&self) -> u32 {
+        self.cubes
+            .keys()
+            .fold(0, |acc, k| acc + *self.is_active(k) as u32)
+    }
+
+    fn is_active(&self, pos: &Position) -> &bool {
+        self.cubes.get(pos).unwrap_or(&false)
+    }
+
+    #[allow(dead_code)]
+    fn pprint(&self) {
+        let width = self.planar_width as i32;
+        let depth = self.depth as i32;
+        for w in self.w_range(depth) {
+            for z in -depth..=depth {
+                match self.dimensions {
+                    Dimensions::Three => println!("z={}", z),
+                    Dimensions::Four => println!("z={}, w={}", z, w),
+                }
+                for x in -width..=width {
+                    for y in -width..=width {
+                        if *self.is_active(&(x, y, z, w)) {
+                            print!("# ");
+                        } else {
+                            print!(". ");
+                        }
+                    }
+                    println!();
+                }
+                println!();
+            }
+        }
+    }
+}
+
+/// Each generation can reach one step further out than the last, so the
+/// universe grows by one in every direction.
+impl Space for Universe {
+    type Position = Position;
+    type State = bool;
+
+    fn positions(&self) -> Vec<Position> {
+        let width = self.planar_width as i32 + 1;
+        let depth = self.depth as i32 + 1;
+        let mut positions = Vec::new();
+        for x in -width..=width {
+            for y in -width..=width {
+                for z in -depth..=depth {
+                    for w in self.w_range(depth) {
+                        positions.push((x, y, z, w));
+                    }
+                }
+            }
+        }
+        positions
+    }
+
+    fn get(&self, pos: Position) -> bool {
+        *self.is_active(&pos)
+    }
+
+    fn update(&self, changes: Vec<(Position, bool)>) -> Universe {
+        let mut cubes = self.cubes.clone();
+        cubes.extend(changes);
+        Universe {
+            cubes,
+            planar_width: self.planar_width + 1,
+            depth: self.depth + 1,
+            dimensions: self.dimensions,
+        }
+    }
+}
+
+/// Places the initial slice (`true` where a cube is active) at z = w = 0,
+/// centred on the origin.
+fn parse_universe(slice: &Grid<bool>) -> Universe {
+    let width = (slice.width().max(slice.height()) / 2) as i32;
+    let cubes = slice
+        .positions()
+        .map(|(col, row)| {
+            let pos = (row as i32 - width, col as i32 - width, 0, 0);
+            (pos, slice[(col, row)])
+        })
+        .collect::<HashMap<Position, bool>>();
+
+    Universe {
+        cubes,
+        planar_width: width as u32,
+        depth: 0,
+        dimensions: Dimensions::Four,
+    }
+}
+
+/// Runs the six-cycle boot process, returning how many cubes are left active.
+fn boot(universe: &Universe, dimensions: Dimensions) -> u32 {
+    let rule = |active, neighbors: &[bool]| {
+        let num_active_neighbors = neighbors.iter().filter(|&&active| active).count();
+        match active {
+            true => num_active_neighbors == 2 || num_active_neighbors == 3,
+            false => num_active_neighbors == 3,
+        }
+    };
+    let mut automaton = Automaton::new(
+        universe.with_dimensions(dimensions),
+        Universe::neighbors,
+        rule,
+    );
+    automaton.run(6);
+    automaton.space.get_num_active()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Universe;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let slice = Grid::parse_str(content, |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(parse_universe(&slice))
+    }
+
+    fn part1(universe: &Self::Input) -> Result<u32, AocError> {
+        Ok(boot(universe, Dimensions::Three))
+    }
+
+    fn part2(universe: &Self::Input) -> Result<u32, AocError> {
+        Ok(boot(universe, Dimensions::Four))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day17::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day17::part1(&input).unwrap(), 112);
+        assert_eq!(Day17::part2(&input).unwrap(), 848);
+    }
+}
@@ -0,0 +1,288 @@
+use common::error::{parse_lines, AocError};
+use common::solution::Solution;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    fn apply(self, a: u64, b: u64) -> Option<u64> {
+        match self {
+            Op::Add => a.checked_add(b),
+            Op::Mul => a.checked_mul(b),
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Add => write!(f, "+"),
+            Op::Mul => write!(f, "*"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum TokenKind {
+    Num(u64),
+    Op(Op),
+    Open,
+    Close,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Token {
+    kind: TokenKind,
+    /// 1-based, for error messages.
+    column: usize,
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, AocError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some((idx, ch)) = chars.next() {
+        let kind = match ch {
+            ' ' => continue,
+            '+' => TokenKind::Op(Op::Add),
+            '*' => TokenKind::Op(Op::Mul),
+            '(' => TokenKind::Open,
+            ')' => TokenKind::Close,
+            '0'..='9' => {
+                let mut end = idx + 1;
+                while let Some(&(next, '0'..='9')) = chars.peek() {
+                    end = next + 1;
+                    chars.next();
+                }
+                TokenKind::Num(
+                    line[idx..end]
+                        .parse::<u64>()
+                        .map_err(|err| AocError::from(err).at_column(idx + 1))?,
+                )
+            }
+            _ => return Err(AocError::parse(format!("unexpected `{}`", ch)).at_column(idx + 1)),
+        };
+        tokens.push(Token {
+            kind,
+            column: idx + 1,
+        });
+    }
+    Ok(tokens)
+}
+
+/// How tightly each operator binds; higher binds tighter.
+#[derive(Debug, Copy, Clone)]
+pub struct Precedence {
+    pub add: u8,
+    pub mul: u8,
+}
+
+impl Precedence {
+    /// Part 1: evaluate strictly left to right.
+    pub const EQUAL: Precedence = Precedence { add: 1, mul: 1 };
+    /// Part 2: addition before multiplication.
+    pub const ADDITION_FIRST: Precedence = Precedence { add: 2, mul: 1 };
+
+    fn of(&self, op: Op) -> u8 {
+        match op {
+            Op::Add => self.add,
+            Op::Mul => self.mul,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expr {
+    Num(u64),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn eval(&self) -> Result<u64, AocError> {
+        match self {
+            Expr::Num(n) => Ok(*n),
+            Expr::Binary(op, a, b) => op
+                .apply(a.eval()?, b.eval()?)
+                .ok_or_else(|| AocError::no_solution(format!("`{}` overflows", self))),
+        }
+    }
+}
+
+/// Fully parenthesized, so the precedence that was applied is visible.
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Num(n) => write!(f, "{}", n),
+            Expr::Binary(op, a, b) => write!(f, "({} {} {})", a, op, b),
+        }
+    }
+}
+
+/// A precedence-climbing parser over one line's tokens.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    precedence: Precedence,
+    /// Where the line ends, to point at when a token is missing.
+    end_column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn column(&self) -> usize {
+        self.peek().map_or(self.end_column, |token| token.column)
+    }
+
+    fn parse_atom(&mut self) -> Result<Expr, AocError> {
+        let token = self.peek();
+        self.pos += 1;
+        match token.map(|token| token.kind) {
+            Some(TokenKind::Num(n)) => Ok(Expr::Num(n)),
+            Some(TokenKind::Open) => {
+                let expr = self.parse_expr(0)?;
+                match self.peek() {
+                    Some(Token {
+                        kind: TokenKind::Close,
+                        ..
+                    }) => {
+                        self.pos += 1;
+                        Ok(expr)
+                    }
+                    _ => Err(AocError::parse("expected `)`").at_column(self.column())),
+                }
+            }
+            _ => {
+                self.pos -= 1;
+                Err(AocError::parse("expected a number or `(`").at_column(self.column()))
+            }
+        }
+    }
+
+    /// Parses operators binding at least as tightly as `min_precedence`.
+    fn parse_expr(&mut self, min_precedence: u8) -> Result<Expr, AocError> {
+        let mut lhs = self.parse_atom()?;
+        while let Some(Token {
+            kind: TokenKind::Op(op),
+            ..
+        }) = self.peek()
+        {
+            let precedence = self.precedence.of(op);
+            if precedence < min_precedence {
+                break;
+            }
+            self.pos += 1;
+            // Binding the right-hand side one level tighter makes operators
+            // of equal precedence associate to the left.
+            let rhs = self.parse_expr(precedence + 1)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    tokens: Vec<Token>,
+    len: usize,
+}
+
+impl Line {
+    pub fn parse(&self, precedence: Precedence) -> Result<Expr, AocError> {
+        let mut parser = Parser {
+            tokens: &self.tokens,
+            pos: 0,
+            precedence,
+            end_column: self.len + 1,
+        };
+        let expr = parser.parse_expr(0)?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(AocError::parse("unexpected token").at_column(token.column)),
+        }
+    }
+}
+
+fn sum(lines: &[Line], precedence: Precedence) -> Result<u64, AocError> {
+    lines.iter().try_fold(0u64, |acc, line| {
+        let value = line.parse(precedence)?.eval()?;
+        acc.checked_add(value)
+            .ok_or_else(|| AocError::no_solution("The sum overflows"))
+    })
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Line>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, |text| {
+            let line = Line {
+                tokens: tokenize(text)?,
+                len: text.len(),
+            };
+            // Whether a line is well-formed doesn't depend on precedence.
+            line.parse(Precedence::EQUAL)?;
+            Ok(line)
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u64, AocError> {
+        sum(lines, Precedence::EQUAL)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u64, AocError> {
+        sum(lines, Precedence::ADDITION_FIRST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_one(text: &str, precedence: Precedence) -> Result<Expr, AocError> {
+        let mut lines = Day18::parse(text)?;
+        lines.remove(0).parse(precedence)
+    }
+
+    #[test]
+    fn example() {
+        let input = Day18::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day18::part1(&input).unwrap(), 26457);
+        assert_eq!(Day18::part2(&input).unwrap(), 694173);
+    }
+
+    #[test]
+    fn pretty_print() {
+        let text = "1 + 2 * (3 + 4)";
+        let expr = parse_one(text, Precedence::EQUAL).unwrap();
+        assert_eq!(expr.to_string(), "((1 + 2) * (3 + 4))");
+        let expr = parse_one("2 * 3 + 4", Precedence::ADDITION_FIRST).unwrap();
+        assert_eq!(expr.to_string(), "(2 * (3 + 4))");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day18::parse("1 + 2\n(1 + 2 * 3\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(11));
+
+        let err = Day18::parse("1 + 2)").unwrap_err();
+        assert_eq!(err.column, Some(6));
+
+        let err = Day18::parse("1 - 2").unwrap_err();
+        assert_eq!(err.column, Some(3));
+
+        let err = Day18::parse("1 + * 2").unwrap_err();
+        assert_eq!(err.column, Some(5));
+    }
+}
@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day18::Day18;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day18>(&name, &content);
+}
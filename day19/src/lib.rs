@@ -0,0 +1,252 @@
+use common::error::{parse_list, parse_number, AocError};
+use common::line::FromLine;
+use common::records;
+use common::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+#[derive(FromLine)]
+#[pattern(r#"(?P<id>\d+):\s"(?P<ch>.)""#)]
+struct CharRule {
+    id: usize,
+    ch: char,
+}
+
+#[derive(FromLine)]
+#[pattern(r"(?P<id>\d+):(?P<alts>(?:\s(?:\d+|\|))+)")]
+struct SeqRule {
+    id: usize,
+    alts: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Char(char),
+    /// Matches any one of the sequences of rules.
+    Alt(Vec<Vec<usize>>),
+}
+
+fn parse_rule(line: &str) -> Result<(usize, Rule), AocError> {
+    if let Some(CharRule { id, ch }) = CharRule::from_line(line).transpose()? {
+        return Ok((id, Rule::Char(ch)));
+    }
+    let SeqRule { id, alts } = SeqRule::from_line(line).unwrap_or_else(|| {
+        Err(AocError::parse(
+            "expected `<id>: \"<char>\"` or `<id>: <ids> | <ids>`",
+        ))
+    })?;
+    // `alts` runs to the end of the line, and is all ASCII.
+    let head = line.chars().count() - alts.len();
+    let seqs = parse_list(&alts, "|", |seq| {
+        let indent = seq.len() - seq.trim_start().len();
+        if seq.trim().is_empty() {
+            return Err(AocError::parse("empty alternative"));
+        }
+        parse_list(seq.trim(), " ", |id| parse_number(id, 1))
+            .map_err(|err| err.offset_column(indent))
+    })
+    .map_err(|err| err.offset_column(head))?;
+    Ok((id, Rule::Alt(seqs)))
+}
+
+/// A backtracking matcher for a numbered grammar.
+///
+/// Rules may be recursive, as long as every recursive path consumes input
+/// before it recurses (`8: 42 | 42 8` is fine, `8: 8 42` is rejected).
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    rules: HashMap<usize, Rule>,
+}
+
+impl Grammar {
+    pub fn new() -> Grammar {
+        Grammar {
+            rules: HashMap::new(),
+        }
+    }
+
+    /// Adds (or replaces) a rule written as in the puzzle input.
+    pub fn add(&mut self, line: &str) -> Result<(), AocError> {
+        let (id, rule) = parse_rule(line)?;
+        self.rules.insert(id, rule);
+        Ok(())
+    }
+
+    /// The first rule referenced without being defined, if any.
+    fn undefined_rule(&self) -> Option<usize> {
+        self.rules
+            .values()
+            .flat_map(|rule| match rule {
+                Rule::Char(_) => Vec::new(),
+                Rule::Alt(seqs) => seqs.iter().flatten().copied().collect(),
+            })
+            .find(|id| !self.rules.contains_key(id))
+    }
+
+    /// A rule that can lead back to itself without consuming any input, if
+    /// any. Every rule consumes at least one character, so only the first
+    /// rule of each sequence starts where its parent does.
+    fn left_recursive_rule(&self) -> Option<usize> {
+        let mut ids: Vec<usize> = self.rules.keys().copied().collect();
+        ids.sort_unstable();
+        let mut done = HashSet::new();
+        ids.into_iter()
+            .find_map(|id| self.leads_back(id, &mut Vec::new(), &mut done))
+    }
+
+    /// The first rule on `path` that `rule` leads back to, if any.
+    fn leads_back(
+        &self,
+        rule: usize,
+        path: &mut Vec<usize>,
+        done: &mut HashSet<usize>,
+    ) -> Option<usize> {
+        if path.contains(&rule) {
+            return Some(rule);
+        }
+        if done.contains(&rule) {
+            return None;
+        }
+        path.push(rule);
+        let found = match self.rules.get(&rule) {
+            Some(Rule::Alt(seqs)) => seqs
+                .iter()
+                .find_map(|seq| self.leads_back(seq[0], path, done)),
+            _ => None,
+        };
+        path.pop();
+        done.insert(rule);
+        found
+    }
+
+    /// Every position at which `rule` can end, having started at `start`.
+    fn ends(&self, rule: usize, message: &[char], start: usize) -> Vec<usize> {
+        match &self.rules[&rule] {
+            Rule::Char(ch) => match message.get(start) {
+                Some(c) if c == ch => vec![start + 1],
+                _ => Vec::new(),
+            },
+            Rule::Alt(seqs) => seqs
+                .iter()
+                .flat_map(|seq| self.seq_ends(seq, message, start))
+                .collect(),
+        }
+    }
+
+    fn seq_ends(&self, seq: &[usize], message: &[char], start: usize) -> Vec<usize> {
+        seq.iter().fold(vec![start], |starts, &rule| {
+            starts
+                .into_iter()
+                .filter(|&pos| pos < message.len())
+                .flat_map(|pos| self.ends(rule, message, pos))
+                .collect()
+        })
+    }
+
+    /// Whether `rule` matches the whole of `message`.
+    pub fn matches(&self, rule: usize, message: &str) -> bool {
+        let message: Vec<char> = message.chars().collect();
+        self.ends(rule, &message, 0).contains(&message.len())
+    }
+}
+
+impl Default for Grammar {
+    fn default() -> Self {
+        Grammar::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct Puzzle {
+    grammar: Grammar,
+    messages: Vec<String>,
+}
+
+fn count_matches(grammar: &Grammar, messages: &[String]) -> Result<usize, AocError> {
+    if let Some(id) = grammar.undefined_rule() {
+        return Err(AocError::parse(format!("rule {} is never defined", id)));
+    }
+    if let Some(id) = grammar.left_recursive_rule() {
+        return Err(AocError::parse(format!(
+            "rule {} is left-recursive, so it would never finish matching",
+            id
+        )));
+    }
+    if !grammar.rules.contains_key(&0) {
+        return Err(AocError::parse("rule 0 is never defined"));
+    }
+    Ok(messages
+        .iter()
+        .filter(|message| grammar.matches(0, message))
+        .count())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let records = records::read_exactly(content, &["rules", "messages"])?;
+        let mut grammar = Grammar::new();
+        records[0].section().parse_lines(|line| grammar.add(line))?;
+        let messages = records[1]
+            .section()
+            .parse_lines(|line| Ok(line.to_owned()))?;
+        Ok(Puzzle { grammar, messages })
+    }
+
+    fn part1(puzzle: &Self::Input) -> Result<usize, AocError> {
+        count_matches(&puzzle.grammar, &puzzle.messages)
+    }
+
+    fn part2(puzzle: &Self::Input) -> Result<usize, AocError> {
+        let mut grammar = puzzle.grammar.clone();
+        grammar.add("8: 42 | 42 8")?;
+        grammar.add("11: 42 31 | 42 11 31")?;
+        count_matches(&grammar, &puzzle.messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day19::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day19::part1(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn recursive_example() {
+        let input = Day19::parse(include_str!("../input/input2.txt")).unwrap();
+        assert_eq!(Day19::part1(&input).unwrap(), 3);
+        assert_eq!(Day19::part2(&input).unwrap(), 12);
+    }
+
+    #[test]
+    fn invalid_rule() {
+        let err = Day19::parse("0: 1 2\n1: \"a\"\n2: 1 x\n\na\n").unwrap_err();
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn left_recursion() {
+        let input = Day19::parse("0: 0 1 | 1\n1: \"a\"\n\naa\n").unwrap();
+        let err = Day19::part1(&input).unwrap_err();
+        assert_eq!(
+            err.message,
+            "rule 0 is left-recursive, so it would never finish matching"
+        );
+
+        // 1 leads back to itself through 2.
+        let input = Day19::parse("0: 1\n1: 2 3 | 3\n2: 1 3\n3: \"a\"\n\naa\n").unwrap();
+        let err = Day19::part1(&input).unwrap_err();
+        assert_eq!(
+            err.message,
+            "rule 1 is left-recursive, so it would never finish matching"
+        );
+    }
+}
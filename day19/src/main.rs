@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day19::Day19;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day19>(&name, &content);
+}
@@ -0,0 +1,98 @@
+use common::error::{parse_lines, parse_number, AocError};
+use common::solution::Solution;
+
+fn sled_rental_password_check(
+    min_count: usize,
+    max_count: usize,
+    letter: char,
+    password: String,
+) -> bool {
+    let count = password.chars().filter(|&ch| ch == letter).count();
+    count >= min_count && count <= max_count
+}
+
+fn toboggan_corporate_policy_check(
+    pos_a: usize,
+    pos_b: usize,
+    letter: char,
+    password: String,
+) -> bool {
+    password
+        .chars()
+        .enumerate()
+        .filter(|t| (t.0 + 1 == pos_a || t.0 + 1 == pos_b) && letter == t.1)
+        .count()
+        == 1
+}
+
+pub struct Entry {
+    a: usize,
+    b: usize,
+    letter: char,
+    password: String,
+}
+
+fn parse_entry(line: &str) -> Result<Entry, AocError> {
+    let malformed = || AocError::parse("expected `<a>-<b> <letter>: <password>`");
+    let parts = line.split(' ').collect::<Vec<_>>();
+    if parts.len() != 3 {
+        return Err(malformed());
+    }
+    let (a, b) = parts[0].split_once('-').ok_or_else(malformed)?;
+    let mut letter = parts[1].chars();
+    let entry = Entry {
+        a: parse_number(a, 1)?,
+        b: parse_number(b, a.len() + 2)?,
+        letter: letter.next().ok_or_else(malformed)?,
+        password: parts[2].to_string(),
+    };
+    if letter.as_str() != ":" {
+        return Err(malformed().at_column(parts[0].len() + 2));
+    }
+    Ok(entry)
+}
+
+fn count_valid_passwords(
+    entries: &[Entry],
+    strategy: fn(usize, usize, char, String) -> bool,
+) -> usize {
+    entries
+        .iter()
+        .filter(|entry| strategy(entry.a, entry.b, entry.letter, entry.password.clone()))
+        .count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, parse_entry)
+    }
+
+    fn part1(entries: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_valid_passwords(entries, sled_rental_password_check))
+    }
+
+    fn part2(entries: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_valid_passwords(
+            entries,
+            toboggan_corporate_policy_check,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day2::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day2::part1(&input).unwrap(), 2);
+        assert_eq!(Day2::part2(&input).unwrap(), 1);
+    }
+}
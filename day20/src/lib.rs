@@ -0,0 +1,241 @@
+use common::error::{parse_number, AocError};
+use common::grid::Grid;
+use common::records::{records, Section};
+use common::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+pub struct Tile {
+    id: u64,
+    grid: Grid<bool>,
+}
+
+fn parse_tile(section: &Section<'_>) -> Result<Tile, AocError> {
+    let (header, pixels) = section.split_header();
+    let id = header
+        .strip_prefix("Tile ")
+        .and_then(|rest| rest.strip_suffix(':'))
+        .ok_or_else(|| AocError::parse("expected `Tile <id>:`"))
+        .and_then(|id| parse_number(id, "Tile ".len() + 1))
+        .map_err(|err| err.at_line(section.line).with_text(header))?;
+    let grid = Grid::parse(&pixels, |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if grid.width() != grid.height() || grid.width() < 3 {
+        return Err(
+            AocError::parse("tiles must be square, and at least 3 pixels wide")
+                .at_line(section.line)
+                .with_text(header),
+        );
+    }
+    Ok(Tile { id, grid })
+}
+
+/// An edge, read the same way whichever side of the tile faces up.
+fn signature(edge: Vec<bool>) -> Vec<bool> {
+    let reversed: Vec<bool> = edge.iter().rev().copied().collect();
+    edge.min(reversed)
+}
+
+fn edges(grid: &Grid<bool>) -> Vec<Vec<bool>> {
+    vec![
+        grid.row(0),
+        grid.column(grid.width() - 1),
+        grid.row(grid.height() - 1),
+        grid.column(0),
+    ]
+}
+
+/// Which tiles have each edge signature.
+fn edge_index(tiles: &[Tile]) -> HashMap<Vec<bool>, Vec<usize>> {
+    let mut index: HashMap<Vec<bool>, Vec<usize>> = HashMap::new();
+    for (idx, tile) in tiles.iter().enumerate() {
+        for edge in edges(&tile.grid) {
+            index.entry(signature(edge)).or_default().push(idx);
+        }
+    }
+    index
+}
+
+fn is_outer(edge: Vec<bool>, index: &HashMap<Vec<bool>, Vec<usize>>) -> bool {
+    index[&signature(edge)].len() == 1
+}
+
+/// Corner tiles are the ones with two edges that match no other tile.
+fn corners(tiles: &[Tile], index: &HashMap<Vec<bool>, Vec<usize>>) -> Vec<usize> {
+    (0..tiles.len())
+        .filter(|&idx| {
+            edges(&tiles[idx].grid)
+                .into_iter()
+                .filter(|edge| is_outer(edge.clone(), index))
+                .count()
+                == 2
+        })
+        .collect()
+}
+
+/// Lays the tiles out so that neighbouring edges match, and joins them into
+/// one image without their borders.
+fn assemble(tiles: &[Tile]) -> Result<Grid<bool>, AocError> {
+    let size = (1..=tiles.len())
+        .find(|size| size * size >= tiles.len())
+        .filter(|size| size * size == tiles.len())
+        .ok_or_else(|| AocError::no_solution("The tiles don't form a square"))?;
+    let index = edge_index(tiles);
+    let start = *corners(tiles, &index)
+        .first()
+        .ok_or_else(|| AocError::no_solution("There are no corner tiles"))?;
+
+    let mut placed: Vec<Vec<Grid<bool>>> = Vec::new();
+    let mut used = vec![false; tiles.len()];
+    used[start] = true;
+    for y in 0..size {
+        let mut row: Vec<Grid<bool>> = Vec::new();
+        for x in 0..size {
+            let left = row.last().map(|tile| tile.column(tile.width() - 1));
+            let above = placed
+                .last()
+                .map(|tiles: &Vec<Grid<bool>>| tiles[x].row(tiles[x].height() - 1));
+            let fits = |grid: &Grid<bool>| {
+                left.as_ref().is_none_or(|left| grid.column(0) == *left)
+                    && above.as_ref().is_none_or(|above| grid.row(0) == *above)
+            };
+
+            let grid =
+                if (x, y) == (0, 0) {
+                    // Turn the first corner so its unmatched edges face outwards.
+                    tiles[start].grid.orientations().into_iter().find(|grid| {
+                        is_outer(grid.row(0), &index) && is_outer(grid.column(0), &index)
+                    })
+                } else {
+                    let edge = left.clone().or_else(|| above.clone()).unwrap();
+                    let found = index[&signature(edge)]
+                        .iter()
+                        .filter(|&&idx| !used[idx])
+                        .find_map(|&idx| {
+                            let grid = tiles[idx].grid.orientations().into_iter().find(&fits)?;
+                            Some((idx, grid))
+                        });
+                    found.map(|(idx, grid)| {
+                        used[idx] = true;
+                        grid
+                    })
+                };
+            row.push(grid.ok_or_else(|| {
+                AocError::no_solution(format!("No tile fits at row {}, column {}", y, x))
+            })?);
+        }
+        placed.push(row);
+    }
+
+    let rows = placed
+        .iter()
+        .flat_map(|tiles| {
+            let inner: Vec<Grid<bool>> = tiles
+                .iter()
+                .map(|tile| tile.crop(1, 1, tile.width() - 2, tile.height() - 2))
+                .collect();
+            (0..inner[0].height()).map(move |y| inner.iter().flat_map(|tile| tile.row(y)).collect())
+        })
+        .collect();
+    Ok(Grid::from_rows(rows))
+}
+
+/// How many `#`s are not part of a sea monster, in whichever orientation
+/// of the image has monsters in it.
+fn roughness(image: &Grid<bool>) -> Result<usize, AocError> {
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, ch)| *ch == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    let (width, height) = (SEA_MONSTER[0].len(), SEA_MONSTER.len());
+
+    for image in image.orientations() {
+        let mut covered: HashSet<(usize, usize)> = HashSet::new();
+        for y in 0..=image.height().saturating_sub(height) {
+            for x in 0..=image.width().saturating_sub(width) {
+                if monster
+                    .iter()
+                    .all(|&(dx, dy)| image.get(x + dx, y + dy) == Some(&true))
+                {
+                    covered.extend(monster.iter().map(|&(dx, dy)| (x + dx, y + dy)));
+                }
+            }
+        }
+        if !covered.is_empty() {
+            return Ok(image.cells().filter(|&&pixel| pixel).count() - covered.len());
+        }
+    }
+    Err(AocError::no_solution("There are no sea monsters"))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let tiles = records(content.as_bytes())
+            .map(|record| parse_tile(&record?.section()))
+            .collect::<Result<Vec<Tile>, AocError>>()?;
+        if let Some(tile) = tiles
+            .iter()
+            .find(|tile| tile.grid.width() != tiles[0].grid.width())
+        {
+            return Err(AocError::parse(format!(
+                "tile {} isn't the same size as tile {}",
+                tile.id, tiles[0].id
+            )));
+        }
+        Ok(tiles)
+    }
+
+    fn part1(tiles: &Self::Input) -> Result<u64, AocError> {
+        let corners = corners(tiles, &edge_index(tiles));
+        if corners.len() != 4 {
+            return Err(AocError::no_solution(format!(
+                "Expected 4 corner tiles, found {}",
+                corners.len()
+            )));
+        }
+        Ok(corners.iter().map(|&idx| tiles[idx].id).product())
+    }
+
+    fn part2(tiles: &Self::Input) -> Result<usize, AocError> {
+        roughness(&assemble(tiles)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day20::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day20::part1(&input).unwrap(), 20899048083289);
+        assert_eq!(Day20::part2(&input).unwrap(), 273);
+    }
+
+    #[test]
+    fn assembled_size() {
+        let input = Day20::parse(include_str!("../input/input1.txt")).unwrap();
+        let image = assemble(&input).unwrap();
+        assert_eq!((image.width(), image.height()), (24, 24));
+    }
+}
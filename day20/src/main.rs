@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day20::Day20;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day20>(&name, &content);
+}
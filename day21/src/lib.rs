@@ -0,0 +1,101 @@
+use common::error::{parse_lines, AocError};
+use common::sets;
+use common::solution::Solution;
+use std::collections::{BTreeMap, HashSet};
+
+pub struct Food {
+    ingredients: HashSet<String>,
+    allergens: Vec<String>,
+}
+
+fn parse_food(line: &str) -> Result<Food, AocError> {
+    let (ingredients, allergens) = match line.split_once(" (contains ") {
+        Some((ingredients, rest)) => {
+            let allergens = rest
+                .strip_suffix(')')
+                .ok_or_else(|| AocError::parse("expected `)`").at_column(line.len() + 1))?;
+            (
+                ingredients,
+                allergens.split(", ").map(str::to_owned).collect(),
+            )
+        }
+        None => (line, Vec::new()),
+    };
+    if let Some(idx) = ingredients.find(|c: char| !c.is_ascii_lowercase() && c != ' ') {
+        return Err(
+            AocError::parse("expected ingredients, then `(contains <allergens>)`")
+                .at_column(idx + 1),
+        );
+    }
+    Ok(Food {
+        ingredients: ingredients.split_whitespace().map(str::to_owned).collect(),
+        allergens,
+    })
+}
+
+/// For each allergen, the ingredients that could contain it: those in every
+/// food that lists the allergen.
+fn candidates(foods: &[Food]) -> BTreeMap<&str, HashSet<String>> {
+    let mut allergens: BTreeMap<&str, Vec<&HashSet<String>>> = BTreeMap::new();
+    for food in foods {
+        for allergen in &food.allergens {
+            allergens
+                .entry(allergen)
+                .or_default()
+                .push(&food.ingredients);
+        }
+    }
+    allergens
+        .into_iter()
+        .map(|(allergen, lists)| (allergen, sets::intersection(lists)))
+        .collect()
+}
+
+/// Works out which ingredient contains each allergen.
+fn resolve(foods: &[Food]) -> Result<BTreeMap<&str, String>, AocError> {
+    sets::eliminate(candidates(foods)).map_err(|allergen| {
+        AocError::no_solution(format!(
+            "Couldn't work out which ingredient contains {}",
+            allergen
+        ))
+    })
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Food>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, parse_food)
+    }
+
+    fn part1(foods: &Self::Input) -> Result<usize, AocError> {
+        let unsafe_ingredients = sets::union(candidates(foods).values());
+        Ok(foods
+            .iter()
+            .flat_map(|food| &food.ingredients)
+            .filter(|ingredient| !unsafe_ingredients.contains(*ingredient))
+            .count())
+    }
+
+    fn part2(foods: &Self::Input) -> Result<String, AocError> {
+        // Sorted by allergen, which the map already is.
+        let dangerous: Vec<String> = resolve(foods)?.into_values().collect();
+        Ok(dangerous.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day21::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day21::part1(&input).unwrap(), 5);
+        assert_eq!(Day21::part2(&input).unwrap(), "mxmxvkd,sqjhc,fvjkl");
+    }
+}
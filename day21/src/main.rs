@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day21::Day21;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day21>(&name, &content);
+}
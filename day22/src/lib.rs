@@ -0,0 +1,272 @@
+use common::error::{parse_number, AocError};
+use common::records;
+use common::records::Section;
+use common::solution::Solution;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+type Deck = VecDeque<u32>;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Player {
+    One,
+    Two,
+}
+
+impl Display for Player {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Player::One => write!(f, "1"),
+            Player::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Decks {
+    one: Deck,
+    two: Deck,
+}
+
+fn parse_deck(section: &Section<'_>, header: &str) -> Result<Deck, AocError> {
+    let cards = section.after_header(header)?;
+    Ok(cards.parse_lines(|line| parse_number(line, 1))?.into())
+}
+
+fn format_deck(deck: &Deck) -> String {
+    let cards: Vec<String> = deck.iter().map(|card| card.to_string()).collect();
+    cards.join(", ")
+}
+
+fn score(deck: &Deck) -> u64 {
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, &card)| (idx as u64 + 1) * card as u64)
+        .sum()
+}
+
+/// One whole match, of plain or recursive combat.
+struct Combat<'a> {
+    recursive: bool,
+    games: usize,
+    /// The winner of every sub-game played so far, by its starting decks.
+    winners: HashMap<(Deck, Deck), Player>,
+    /// Where to write the walkthrough the puzzle shows, if anywhere.
+    trace: Option<&'a mut Vec<String>>,
+}
+
+impl<'a> Combat<'a> {
+    fn new(recursive: bool, trace: Option<&'a mut Vec<String>>) -> Combat<'a> {
+        Combat {
+            recursive,
+            games: 0,
+            winners: HashMap::new(),
+            trace,
+        }
+    }
+
+    fn log<F: FnOnce() -> String>(&mut self, line: F) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(line());
+        }
+    }
+
+    /// Plays a game to the end, returning the winner and both final decks.
+    fn play(&mut self, mut one: Deck, mut two: Deck) -> Result<(Player, Deck, Deck), AocError> {
+        self.games += 1;
+        let game = self.games;
+        if self.recursive {
+            self.log(|| format!("=== Game {} ===", game));
+            self.log(String::new);
+        }
+
+        let mut seen: HashSet<(Deck, Deck)> = HashSet::new();
+        let mut round = 0;
+        let winner = loop {
+            if two.is_empty() {
+                break Player::One;
+            }
+            if one.is_empty() {
+                break Player::Two;
+            }
+            // Recursive combat goes to player 1 as soon as a round repeats;
+            // plain combat would go round in circles forever.
+            if !seen.insert((one.clone(), two.clone())) {
+                if self.recursive {
+                    break Player::One;
+                }
+                return Err(AocError::no_solution(format!(
+                    "The decks repeat after round {}, so the game never ends",
+                    round
+                )));
+            }
+
+            round += 1;
+            let header = if self.recursive {
+                format!("-- Round {} (Game {}) --", round, game)
+            } else {
+                format!("-- Round {} --", round)
+            };
+            self.log(|| header);
+            self.log(|| format!("Player 1's deck: {}", format_deck(&one)));
+            self.log(|| format!("Player 2's deck: {}", format_deck(&two)));
+
+            let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+            self.log(|| format!("Player 1 plays: {}", a));
+            self.log(|| format!("Player 2 plays: {}", b));
+
+            let round_winner =
+                if self.recursive && one.len() >= a as usize && two.len() >= b as usize {
+                    self.log(|| "Playing a sub-game to determine the winner...".to_owned());
+                    self.log(String::new);
+                    let winner = self.sub_game(
+                        one.iter().take(a as usize).copied().collect(),
+                        two.iter().take(b as usize).copied().collect(),
+                    )?;
+                    self.log(|| format!("...anyway, back to game {}.", game));
+                    winner
+                } else if a > b {
+                    Player::One
+                } else {
+                    Player::Two
+                };
+            if self.recursive {
+                self.log(|| {
+                    format!(
+                        "Player {} wins round {} of game {}!",
+                        round_winner, round, game
+                    )
+                });
+            } else {
+                self.log(|| format!("Player {} wins the round!", round_winner));
+            }
+            self.log(String::new);
+
+            match round_winner {
+                Player::One => one.extend([a, b].iter()),
+                Player::Two => two.extend([b, a].iter()),
+            }
+        };
+        if self.recursive {
+            self.log(|| format!("The winner of game {} is player {}!", game, winner));
+            self.log(String::new);
+        }
+        Ok((winner, one, two))
+    }
+
+    fn sub_game(&mut self, one: Deck, two: Deck) -> Result<Player, AocError> {
+        // Skipping a remembered game would leave a gap in the walkthrough.
+        if self.trace.is_none() {
+            if let Some(&winner) = self.winners.get(&(one.clone(), two.clone())) {
+                return Ok(winner);
+            }
+        }
+        let (winner, _, _) = self.play(one.clone(), two.clone())?;
+        self.winners.insert((one, two), winner);
+        Ok(winner)
+    }
+}
+
+/// Plays a match, returning the winner's score.
+fn play(decks: &Decks, recursive: bool, trace: Option<&mut Vec<String>>) -> Result<u64, AocError> {
+    let mut combat = Combat::new(recursive, trace);
+    let (winner, one, two) = combat.play(decks.one.clone(), decks.two.clone())?;
+    combat.log(|| "== Post-game results ==".to_owned());
+    combat.log(|| format!("Player 1's deck: {}", format_deck(&one)));
+    combat.log(|| format!("Player 2's deck: {}", format_deck(&two)));
+    Ok(match winner {
+        Player::One => score(&one),
+        Player::Two => score(&two),
+    })
+}
+
+/// The round-by-round walkthrough of both parts, as the puzzle shows it.
+pub fn trace(content: &str) -> Result<String, AocError> {
+    let decks = Day22::parse(content)?;
+    let mut lines = Vec::new();
+    for &recursive in [false, true].iter() {
+        // A game that never ends stops at its first repeat; say so and
+        // carry on with the other part.
+        if let Err(err) = play(&decks, recursive, Some(&mut lines)) {
+            lines.push(err.to_string());
+        }
+        lines.push(String::new());
+    }
+    Ok(lines.join("\n"))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Decks;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let records = records::read_exactly(content, &["player 1's deck", "player 2's deck"])?;
+        let one = parse_deck(&records[0].section(), "Player 1:")?;
+        let two = parse_deck(&records[1].section(), "Player 2:")?;
+        // Rounds can't be decided between equal cards.
+        let mut cards = HashSet::new();
+        if let Some(card) = one
+            .iter()
+            .chain(two.iter())
+            .find(|&&card| !cards.insert(card))
+        {
+            return Err(AocError::parse(format!("card {} appears twice", card)));
+        }
+        Ok(Decks { one, two })
+    }
+
+    fn part1(decks: &Self::Input) -> Result<u64, AocError> {
+        play(decks, false, None)
+    }
+
+    fn part2(decks: &Self::Input) -> Result<u64, AocError> {
+        play(decks, true, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::error::ErrorKind;
+
+    #[test]
+    fn example() {
+        let input = Day22::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day22::part1(&input).unwrap(), 306);
+        assert_eq!(Day22::part2(&input).unwrap(), 291);
+    }
+
+    #[test]
+    fn repeated_rounds_end_the_game() {
+        let input = Day22::parse(include_str!("../input/input2.txt")).unwrap();
+        let err = Day22::part1(&input).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NoSolution);
+        assert_eq!(Day22::part2(&input).unwrap(), 105);
+    }
+
+    #[test]
+    fn walkthrough() {
+        let input = Day22::parse(include_str!("../input/input1.txt")).unwrap();
+        let mut lines = Vec::new();
+        play(&input, true, Some(&mut lines)).unwrap();
+        assert_eq!(
+            lines[..10].join("\n"),
+            "=== Game 1 ===\n\n-- Round 1 (Game 1) --\n\
+             Player 1's deck: 9, 2, 6, 3, 1\n\
+             Player 2's deck: 5, 8, 4, 7, 10\n\
+             Player 1 plays: 9\n\
+             Player 2 plays: 5\n\
+             Player 1 wins round 1 of game 1!\n\n\
+             -- Round 2 (Game 1) --"
+        );
+        assert_eq!(
+            lines.last().unwrap(),
+            "Player 2's deck: 7, 5, 6, 2, 4, 1, 10, 8, 9, 3"
+        );
+    }
+}
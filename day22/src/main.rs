@@ -0,0 +1,13 @@
+use common::{input, solution};
+use day22::Day22;
+
+fn main() {
+    let (name, content, trace) = input::load_traced(env!("CARGO_MANIFEST_DIR"));
+    if trace {
+        match day22::trace(&content) {
+            Ok(trace) => println!("{}", trace),
+            Err(err) => eprintln!("Couldn't trace: {}", err.in_file(&name)),
+        }
+    }
+    solution::print::<Day22>(&name, &content);
+}
@@ -0,0 +1,240 @@
+use common::error::AocError;
+use common::solution::Solution;
+
+/// How big a game is.
+#[derive(Debug, Copy, Clone)]
+pub struct Rules {
+    /// The labelled cups come first, then the rest in order of label.
+    pub cups: usize,
+    pub moves: usize,
+}
+
+impl Rules {
+    pub const PART1: Rules = Rules {
+        cups: 9,
+        moves: 100,
+    };
+    pub const PART2: Rules = Rules {
+        cups: 1_000_000,
+        moves: 10_000_000,
+    };
+}
+
+/// The cups in a circle, as the cup after each one.
+///
+/// Cups are numbered from 1, so `next[0]` is unused. A move only rewires a
+/// few cups, however many there are.
+struct Circle {
+    next: Vec<u32>,
+    current: u32,
+}
+
+impl Circle {
+    fn new(labels: &[u32], cups: usize) -> Result<Circle, AocError> {
+        if cups < labels.len() {
+            return Err(AocError::unsupported(format!(
+                "can't play with {} cups when {} are labelled",
+                cups,
+                labels.len()
+            )));
+        }
+        let order: Vec<u32> = labels
+            .iter()
+            .copied()
+            .chain(labels.len() as u32 + 1..=cups as u32)
+            .collect();
+        let mut next = vec![0; cups + 1];
+        for (idx, &cup) in order.iter().enumerate() {
+            next[cup as usize] = order[(idx + 1) % cups];
+        }
+        Ok(Circle {
+            next,
+            current: order[0],
+        })
+    }
+
+    fn len(&self) -> u32 {
+        self.next.len() as u32 - 1
+    }
+
+    /// Every cup, starting from `cup`.
+    fn from(&self, cup: u32) -> impl Iterator<Item = u32> + '_ {
+        let mut cup = cup;
+        (0..self.len()).map(move |_| {
+            let this = cup;
+            cup = self.next[cup as usize];
+            this
+        })
+    }
+
+    /// Makes one move, returning the cups picked up and where they went.
+    fn step(&mut self) -> ([u32; 3], u32) {
+        let first = self.next[self.current as usize];
+        let second = self.next[first as usize];
+        let third = self.next[second as usize];
+        let picked = [first, second, third];
+
+        let mut destination = self.current;
+        loop {
+            destination = if destination == 1 {
+                self.len()
+            } else {
+                destination - 1
+            };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+
+        self.next[self.current as usize] = self.next[third as usize];
+        self.next[third as usize] = self.next[destination as usize];
+        self.next[destination as usize] = first;
+        self.current = self.next[self.current as usize];
+        (picked, destination)
+    }
+
+    /// The circle as the puzzle shows it after `moves` moves: in the same
+    /// place as it started, with the current cup in brackets.
+    fn show(&self, moves: usize) -> String {
+        let back = (self.len() as usize - moves % self.len() as usize) % self.len() as usize;
+        let start = self.from(self.current).nth(back).unwrap();
+        self.from(start)
+            .map(|cup| {
+                if cup == self.current {
+                    format!("({})", cup)
+                } else {
+                    format!(" {} ", cup)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Plays a game, writing the walkthrough the puzzle shows into `trace`.
+fn play(
+    labels: &[u32],
+    rules: Rules,
+    mut trace: Option<&mut Vec<String>>,
+) -> Result<Circle, AocError> {
+    let mut circle = Circle::new(labels, rules.cups)?;
+    for moves in 0..rules.moves {
+        let before = trace.as_ref().map(|_| circle.show(moves));
+        let (picked, destination) = circle.step();
+        if let Some(trace) = trace.as_mut() {
+            trace.push(format!("-- move {} --", moves + 1));
+            trace.push(format!("cups: {}", before.unwrap()));
+            trace.push(format!(
+                "pick up: {}, {}, {}",
+                picked[0], picked[1], picked[2]
+            ));
+            trace.push(format!("destination: {}", destination));
+            trace.push(String::new());
+        }
+    }
+    if let Some(trace) = trace {
+        trace.push("-- final --".to_owned());
+        trace.push(format!("cups: {}", circle.show(rules.moves)));
+    }
+    Ok(circle)
+}
+
+/// The walkthrough of `moves` moves with just the labelled cups, as the
+/// puzzle shows it.
+pub fn trace(content: &str, moves: usize) -> Result<String, AocError> {
+    let labels = Day23::parse(content)?;
+    let mut lines = Vec::new();
+    play(
+        &labels,
+        Rules {
+            cups: labels.len(),
+            moves,
+        },
+        Some(&mut lines),
+    )?;
+    Ok(lines.join("\n"))
+}
+
+/// The labels on the cups after cup 1, in order.
+pub fn labels_after_one(labels: &[u32], rules: Rules) -> Result<String, AocError> {
+    let circle = play(labels, rules, None)?;
+    Ok(circle.from(1).skip(1).map(|cup| cup.to_string()).collect())
+}
+
+/// The product of the two cups after cup 1.
+pub fn stars(labels: &[u32], rules: Rules) -> Result<u64, AocError> {
+    let circle = play(labels, rules, None)?;
+    let first = circle.next[1];
+    let second = circle.next[first as usize];
+    Ok(first as u64 * second as u64)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<u32>;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let line = content.trim_end();
+        let labels = line
+            .chars()
+            .enumerate()
+            .map(|(idx, ch)| {
+                ch.to_digit(10).ok_or_else(|| {
+                    AocError::parse(format!("expected a digit, found `{}`", ch)).at_column(idx + 1)
+                })
+            })
+            .collect::<Result<Vec<u32>, AocError>>()
+            .map_err(|err| err.at_line(1).with_text(line))?;
+        // The cups must be labelled 1 up to however many there are.
+        let mut sorted = labels.clone();
+        sorted.sort_unstable();
+        if labels.len() < 5 || sorted.iter().zip(1..).any(|(&cup, label)| cup != label) {
+            return Err(
+                AocError::parse("expected each of 1 to n once, for at least 5 cups")
+                    .at_line(1)
+                    .with_text(line),
+            );
+        }
+        Ok(labels)
+    }
+
+    fn part1(labels: &Self::Input) -> Result<String, AocError> {
+        labels_after_one(labels, Rules::PART1)
+    }
+
+    fn part2(labels: &Self::Input) -> Result<u64, AocError> {
+        stars(labels, Rules::PART2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day23::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day23::part1(&input).unwrap(), "67384529");
+        assert_eq!(Day23::part2(&input).unwrap(), 149245887792);
+    }
+
+    #[test]
+    fn ten_moves() {
+        let input = Day23::parse(include_str!("../input/input1.txt")).unwrap();
+        let rules = Rules { cups: 9, moves: 10 };
+        assert_eq!(labels_after_one(&input, rules).unwrap(), "92658374");
+    }
+
+    #[test]
+    fn walkthrough() {
+        let trace = trace(include_str!("../input/input1.txt"), 10).unwrap();
+        let lines: Vec<&str> = trace.lines().collect();
+        assert_eq!(lines[1], "cups: (3) 8  9  1  2  5  4  6  7 ");
+        assert_eq!(lines[2], "pick up: 8, 9, 1");
+        assert_eq!(lines[3], "destination: 2");
+        assert_eq!(lines[6], "cups:  3 (2) 8  9  1  5  4  6  7 ");
+        assert_eq!(lines[lines.len() - 1], "cups:  5 (8) 3  7  4  1  9  2  6 ");
+    }
+}
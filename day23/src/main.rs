@@ -0,0 +1,13 @@
+use common::{input, solution};
+use day23::Day23;
+
+fn main() {
+    let (name, content, trace) = input::load_traced(env!("CARGO_MANIFEST_DIR"));
+    if trace {
+        match day23::trace(&content, day23::Rules::PART1.moves) {
+            Ok(trace) => println!("{}", trace),
+            Err(err) => eprintln!("Couldn't trace: {}", err.in_file(&name)),
+        }
+    }
+    solution::print::<Day23>(&name, &content);
+}
@@ -0,0 +1,182 @@
+use common::error::{parse_lines, AocError};
+use common::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    const ALL: [Direction; 6] = [
+        Direction::East,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+        Direction::NorthEast,
+    ];
+
+    /// How far a step moves in each axial coordinate.
+    fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::East => (1, 0),
+            Direction::SouthEast => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (0, -1),
+            Direction::NorthEast => (1, -1),
+        }
+    }
+}
+
+fn parse_path(line: &str) -> Result<Vec<Direction>, AocError> {
+    let mut path = Vec::new();
+    let mut rest = line;
+    while !rest.is_empty() {
+        let (direction, len) = match rest.as_bytes() {
+            [b'e', ..] => (Direction::East, 1),
+            [b'w', ..] => (Direction::West, 1),
+            [b's', b'e', ..] => (Direction::SouthEast, 2),
+            [b's', b'w', ..] => (Direction::SouthWest, 2),
+            [b'n', b'w', ..] => (Direction::NorthWest, 2),
+            [b'n', b'e', ..] => (Direction::NorthEast, 2),
+            _ => {
+                return Err(AocError::parse("expected e, se, sw, w, nw or ne")
+                    .at_column(line.len() - rest.len() + 1))
+            }
+        };
+        path.push(direction);
+        rest = &rest[len..];
+    }
+    Ok(path)
+}
+
+/// A hexagonal tile, in axial coordinates: `q` runs east, and `r` runs
+/// south-east.
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+struct Hex {
+    q: i32,
+    r: i32,
+}
+
+impl Hex {
+    const REFERENCE: Hex = Hex { q: 0, r: 0 };
+
+    fn step(self, direction: Direction) -> Hex {
+        let (dq, dr) = direction.offset();
+        Hex {
+            q: self.q + dq,
+            r: self.r + dr,
+        }
+    }
+
+    fn neighbors(self) -> impl Iterator<Item = Hex> {
+        Direction::ALL
+            .iter()
+            .map(move |&direction| self.step(direction))
+    }
+}
+
+#[derive(Debug)]
+pub struct Floor {
+    black: HashSet<Hex>,
+}
+
+impl Floor {
+    /// Flips the tile at the end of each path, starting from all white.
+    fn lay(paths: &[Vec<Direction>]) -> Floor {
+        let mut black = HashSet::new();
+        for path in paths {
+            let tile = path.iter().fold(Hex::REFERENCE, |tile, &d| tile.step(d));
+            if !black.remove(&tile) {
+                black.insert(tile);
+            }
+        }
+        Floor { black }
+    }
+
+    fn get_num_black(&self) -> usize {
+        self.black.len()
+    }
+
+    /// Only tiles next to a black tile can be black tomorrow, so those are
+    /// the only ones to count.
+    fn get_num_black_neighbors(&self) -> HashMap<Hex, u32> {
+        let mut counts = HashMap::new();
+        for tile in &self.black {
+            for neighbor in tile.neighbors() {
+                *counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    fn next_state(&self) -> Floor {
+        let black = self
+            .get_num_black_neighbors()
+            .into_iter()
+            .filter(|(tile, num_black)| match self.black.contains(tile) {
+                true => *num_black == 1 || *num_black == 2,
+                false => *num_black == 2,
+            })
+            .map(|(tile, _)| tile)
+            .collect();
+        Floor { black }
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Vec<Direction>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, parse_path)
+    }
+
+    fn part1(paths: &Self::Input) -> Result<usize, AocError> {
+        Ok(Floor::lay(paths).get_num_black())
+    }
+
+    fn part2(paths: &Self::Input) -> Result<usize, AocError> {
+        let mut floor = Floor::lay(paths);
+        for _ in 0..100 {
+            floor = floor.next_state();
+        }
+        Ok(floor.get_num_black())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day24::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day24::part1(&input).unwrap(), 10);
+        assert_eq!(Day24::part2(&input).unwrap(), 2208);
+    }
+
+    #[test]
+    fn paths() {
+        let input = Day24::parse("nwwswee\nesew").unwrap();
+        let floor = Floor::lay(&input[..1]);
+        assert!(floor.black.contains(&Hex::REFERENCE));
+        let floor = Floor::lay(&input[1..]);
+        assert!(floor
+            .black
+            .contains(&Hex::REFERENCE.step(Direction::SouthEast)));
+
+        let err = Day24::parse("esew\nenw\nesx").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(2)));
+    }
+}
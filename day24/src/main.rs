@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day24::Day24;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day24>(&name, &content);
+}
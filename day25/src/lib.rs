@@ -0,0 +1,81 @@
+use common::error::{parse_lines, parse_number, AocError};
+use common::modular::{discrete_log, mod_pow};
+use common::solution::{Part, Solution};
+
+const SUBJECT: u64 = 7;
+const MODULUS: u64 = 20201227;
+
+/// Finds the loop size that turns `SUBJECT` into `key`.
+fn loop_size(key: u64) -> Result<u64, AocError> {
+    if key == 0 || key >= MODULUS {
+        return Err(AocError::no_solution(format!(
+            "Public keys are between 1 and {}, not {}",
+            MODULUS - 1,
+            key
+        )));
+    }
+    discrete_log(SUBJECT, key, MODULUS).ok_or_else(|| {
+        AocError::no_solution(format!("No loop size produces the public key {}", key))
+    })
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let keys = parse_lines(content, |line| parse_number(line, 1))?;
+        if keys.len() != 2 {
+            return Err(AocError::parse(format!(
+                "expected 2 public keys (card and door), found {}",
+                keys.len()
+            )));
+        }
+        Ok(keys)
+    }
+
+    fn part1(keys: &Self::Input) -> Result<u64, AocError> {
+        let (card, door) = (keys[0], keys[1]);
+        let loop_size = loop_size(card)?;
+        mod_pow(door, loop_size, MODULUS)
+            .ok_or_else(|| AocError::no_solution("The modulus must not be 0"))
+    }
+
+    fn part2(_keys: &Self::Input) -> Result<u64, AocError> {
+        Err(AocError::unsupported("day 25 has no part 2"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution;
+
+    #[test]
+    fn example() {
+        let input = Day25::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day25::part1(&input).unwrap(), 14897079);
+    }
+
+    #[test]
+    fn only_part_1() {
+        let report = solution::solve::<Day25>(
+            "input1.txt",
+            include_str!("../input/input1.txt"),
+            Part::BOTH,
+        );
+        let parts: Vec<Part> = report.answers.iter().map(|answer| answer.part).collect();
+        assert_eq!(parts, vec![Part::One]);
+    }
+
+    #[test]
+    fn loop_sizes() {
+        assert_eq!(loop_size(5764801).unwrap(), 8);
+        assert_eq!(loop_size(17807724).unwrap(), 11);
+    }
+}
@@ -0,0 +1,7 @@
+use common::{input, solution};
+use day25::Day25;
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day25>(&name, &content);
+}
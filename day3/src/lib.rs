@@ -0,0 +1,59 @@
+use common::error::AocError;
+use common::grid::Grid;
+use common::solution::Solution;
+
+/// `true` where there's a tree.
+type Map = Grid<bool>;
+
+fn count_trees(map: &Map, right: usize, down: usize) -> usize {
+    (0..map.height())
+        .step_by(down)
+        .enumerate()
+        // The map repeats to the right as far as we need it to.
+        .filter(|&(step, y)| *map.get_wrapping(step * right, y))
+        .count()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let map = Grid::parse_str(content, |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if map.width() == 0 {
+            return Err(AocError::parse("empty row").at_line(1));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize, AocError> {
+        Ok(count_trees(map, 3, 1))
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize, AocError> {
+        let steps = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+        Ok(steps.iter().fold(1, |acc, (right, down)| {
+            acc * count_trees(map, *right, *down)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day3::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day3::part1(&input).unwrap(), 7);
+        assert_eq!(Day3::part2(&input).unwrap(), 336);
+    }
+}
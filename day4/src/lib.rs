@@ -0,0 +1,195 @@
+use common::error::AocError;
+use common::records::records;
+use common::solution::Solution;
+use std::collections::{HashMap, HashSet};
+use std::result::Result;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+enum PassportFieldType {
+    BirthYear,
+    IssueYear,
+    ExpirationYear,
+    Height,
+    HairColor,
+    EyeColor,
+    PassportID,
+    CountryID,
+}
+
+impl FromStr for PassportFieldType {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "byr" => Result::Ok(PassportFieldType::BirthYear),
+            "iyr" => Result::Ok(PassportFieldType::IssueYear),
+            "eyr" => Result::Ok(PassportFieldType::ExpirationYear),
+            "hgt" => Result::Ok(PassportFieldType::Height),
+            "hcl" => Result::Ok(PassportFieldType::HairColor),
+            "ecl" => Result::Ok(PassportFieldType::EyeColor),
+            "pid" => Result::Ok(PassportFieldType::PassportID),
+            "cid" => Result::Ok(PassportFieldType::CountryID),
+            _ => Result::Err(AocError::parse(format!("Invalid field type: {}", s))),
+        }
+    }
+}
+
+impl PassportFieldType {
+    fn check_in_range(data: &str, start: u16, end: u16) -> bool {
+        let value = data.parse::<u16>().unwrap_or(0);
+        value >= start && value <= end
+    }
+
+    pub fn is_valid(&self, value: String) -> bool {
+        match self {
+            PassportFieldType::BirthYear => PassportFieldType::check_in_range(&value, 1920, 2002),
+            PassportFieldType::IssueYear => PassportFieldType::check_in_range(&value, 2010, 2020),
+            PassportFieldType::ExpirationYear => {
+                PassportFieldType::check_in_range(&value, 2020, 2030)
+            }
+            PassportFieldType::Height => {
+                let mut amount = String::new();
+                let mut iter = value.chars().peekable();
+                while iter.peek().unwrap_or(&' ').is_numeric() {
+                    amount.push(iter.next().unwrap());
+                }
+                match iter.collect::<String>().as_str() {
+                    "cm" => PassportFieldType::check_in_range(&amount, 150, 193),
+                    "in" => PassportFieldType::check_in_range(&amount, 59, 76),
+                    _ => false,
+                }
+            }
+            PassportFieldType::HairColor => {
+                let mut chars = value.chars();
+                // Require 7 chars total:
+                // first is #, and the rest are per the pattern below.
+                if chars.next().unwrap_or(' ') != '#' {
+                    return false;
+                }
+                chars
+                    .filter(|ch| matches!(ch, '0'..='9' | 'a'..='f'))
+                    .count()
+                    == 6
+            }
+            PassportFieldType::EyeColor => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+                .iter()
+                .cloned()
+                .collect::<HashSet<&str>>()
+                .contains(value.as_str()),
+            PassportFieldType::PassportID => value.chars().filter(|c| c.is_numeric()).count() == 9,
+            PassportFieldType::CountryID => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Passport {
+    fields: HashMap<PassportFieldType, String>,
+}
+
+impl Passport {
+    fn new() -> Passport {
+        Passport {
+            fields: HashMap::new(),
+        }
+    }
+
+    fn add_field(&mut self, field_type: PassportFieldType, value: String) {
+        self.fields.insert(field_type, value);
+    }
+
+    fn has_required_fields(&self) -> bool {
+        // CountryID is the only optional field.
+        self.fields
+            .keys()
+            .filter(|field| **field != PassportFieldType::CountryID)
+            .count()
+            == 7
+    }
+
+    fn is_valid(&self) -> bool {
+        // Look for 7 valid fields: one for each PassportFieldType,
+        // excluding CountryID since it's optional.
+        self.fields
+            .iter()
+            .filter(|(field, value)| field.is_valid(value.to_string()))
+            .count()
+            == 7
+    }
+}
+
+/// Reads one passport per record. Unknown fields are ignored, but a field
+/// without a `:` or a field given twice is a parse error.
+fn get_passports(content: &str) -> Result<Vec<Passport>, AocError> {
+    let mut passports = Vec::new();
+    for record in records(content.as_bytes()) {
+        let mut passport = Passport::new();
+        for (key, value) in record?.fields()? {
+            // Fields we don't know about don't matter.
+            if let Ok(field_type) = key.parse::<PassportFieldType>() {
+                passport.add_field(field_type, value);
+            }
+        }
+        passports.push(passport);
+    }
+    Ok(passports)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        get_passports(content)
+    }
+
+    fn part1(passports: &Self::Input) -> Result<usize, AocError> {
+        Ok(passports
+            .iter()
+            .filter(|passport| passport.has_required_fields())
+            .count())
+    }
+
+    fn part2(passports: &Self::Input) -> Result<usize, AocError> {
+        Ok(passports
+            .iter()
+            .filter(|passport| passport.is_valid())
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day4::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day4::part1(&input).unwrap(), 2);
+        assert_eq!(Day4::part2(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn invalid_passports() {
+        let input = Day4::parse(include_str!("../input/input2.txt")).unwrap();
+        assert_eq!(Day4::part2(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn valid_passports() {
+        let input = Day4::parse(include_str!("../input/input3.txt")).unwrap();
+        assert_eq!(Day4::part2(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn malformed_fields() {
+        let err = Day4::parse("ecl:gry pid:860033327\nhcl:#fffffd ecl:amb\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(13)));
+        let err = Day4::parse("byr:1937\n\niyr:2017 cid\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(10)));
+    }
+}
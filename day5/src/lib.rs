@@ -0,0 +1,114 @@
+use common::error::{parse_lines, AocError};
+use common::solution::Solution;
+
+fn get_seat_id(line: &str) -> Result<u32, AocError> {
+    if line.len() != 10 {
+        return Err(AocError::parse("expected 10 characters"));
+    }
+    for (idx, ch) in line.chars().enumerate() {
+        let valid = if idx < 7 { "FB" } else { "LR" };
+        if !valid.contains(ch) {
+            return Err(AocError::parse(format!("expected one of `{}`", valid)).at_column(idx + 1));
+        }
+    }
+
+    // "line" is a string of form [F|B]{8}[L|R]{3}.
+    //
+    // Therefore, the last three characters encode
+    // a 3-bit integer (0-7) and the first 8 characters
+    // encode an 8-bit integer (0-127).
+    //
+    // The problem statement asks us to multiple the
+    // second integer by 8 and add it to the first integer.
+    //
+    // Multiplying an integer by 8 is the equivalent of
+    // shifting it left 3 times. Therefore, our formula
+    // becomes:
+    //
+    // bin([F|B]{8}) << 3 + bin([L|R]{3})
+    //
+    // This is equivalent to:
+    //
+    // bin([F|B]{8}) << 3 | bin([L|R]{3})
+    //
+    // Which is itself equivalent to:
+    //
+    // bin([F|B]{8}[L|R]{3})
+    //
+    Ok(u32::from_str_radix(
+        &line
+            .replace('F', "0")
+            .replace('B', "1")
+            .replace('L', "0")
+            .replace('R', "1"),
+        2,
+    )?)
+}
+
+fn get_my_seat_id(seat_ids: &[u32]) -> Option<u32> {
+    seat_ids
+        .windows(2)
+        .find(|pair| pair[1] == pair[0] + 2)
+        .map(|pair| pair[0] + 1)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        let mut seat_ids: Vec<u32> = parse_lines(content, get_seat_id)?;
+        seat_ids.sort_unstable();
+        Ok(seat_ids)
+    }
+
+    fn part1(seat_ids: &Self::Input) -> Result<u32, AocError> {
+        seat_ids
+            .iter()
+            .max()
+            .copied()
+            .ok_or_else(|| AocError::no_solution("There are no boarding passes"))
+    }
+
+    fn part2(seat_ids: &Self::Input) -> Result<u32, AocError> {
+        get_my_seat_id(seat_ids)
+            .ok_or_else(|| AocError::no_solution("There is no gap in the seat ids"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_ids() {
+        assert_eq!(get_seat_id("BFFFBBFRRR").unwrap(), 567);
+        assert_eq!(get_seat_id("FFFBBBFRRR").unwrap(), 119);
+        assert_eq!(get_seat_id("BBFFBBFRLL").unwrap(), 820);
+    }
+
+    #[test]
+    fn invalid_seat() {
+        let err = Day5::parse("BFFFBBFRRR\nBFFFXBFRRR\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(5));
+    }
+
+    #[test]
+    fn example() {
+        let input = Day5::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day5::part1(&input).unwrap(), 820);
+        // There is no example for part 2: the seat is the one gap in the list.
+        assert_eq!(Day5::part2(&vec![3, 4, 5, 7, 8]).unwrap(), 6);
+    }
+
+    #[test]
+    fn gap_anywhere() {
+        assert_eq!(get_my_seat_id(&[3, 5, 6]), Some(4));
+        assert_eq!(get_my_seat_id(&[0, 0, 1]), None);
+        assert_eq!(get_my_seat_id(&[4, 4, 6]), Some(5));
+    }
+}
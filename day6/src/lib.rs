@@ -0,0 +1,54 @@
+use common::error::AocError;
+use common::records::records;
+use common::sets;
+use common::solution::Solution;
+use std::collections::HashSet;
+
+type Group = Vec<HashSet<char>>;
+
+fn count(groups: &[Group], merge: fn(&Group) -> HashSet<char>) -> usize {
+    groups.iter().map(|group| merge(group).len()).sum()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Group>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        records(content.as_bytes())
+            .map(|record| {
+                let record = record?;
+                Ok(record
+                    .lines
+                    .iter()
+                    .map(|line| line.chars().collect())
+                    .collect())
+            })
+            .collect()
+    }
+
+    fn part1(groups: &Self::Input) -> Result<usize, AocError> {
+        // Questions to which anyone in the group answered "yes".
+        Ok(count(groups, |group| sets::union(group)))
+    }
+
+    fn part2(groups: &Self::Input) -> Result<usize, AocError> {
+        // Questions to which everyone in the group answered "yes".
+        Ok(count(groups, |group| sets::intersection(group)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day6::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day6::part1(&input).unwrap(), 11);
+        assert_eq!(Day6::part2(&input).unwrap(), 6);
+    }
+}
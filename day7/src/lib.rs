@@ -0,0 +1,121 @@
+use common::error::{parse_list, AocError};
+use common::line::FromLine;
+use common::solution::Solution;
+use std::collections::HashMap;
+
+#[derive(FromLine)]
+#[pattern(r"(?P<count>\d+)\s(?P<bag_type>\w+\s\w+)\sbags?\.?")]
+struct Contents {
+    count: u32,
+    bag_type: String,
+}
+
+pub struct BaggageRules {
+    rules: HashMap<String, Vec<(String, u32)>>,
+}
+
+impl BaggageRules {
+    fn new() -> BaggageRules {
+        BaggageRules {
+            rules: HashMap::new(),
+        }
+    }
+
+    fn parse_rule(&mut self, line: &str) -> Result<(), AocError> {
+        let keyword = " bags contain ";
+        let idx = line
+            .find(keyword)
+            .ok_or_else(|| AocError::parse("expected `<bag> bags contain <contents>`"))?;
+        let key = line[..idx].to_owned();
+        let (head, contents) = line.split_at(idx + keyword.len());
+        let bag_counts = parse_list(contents, ", ", |s| {
+            if s.ends_with("no other bags.") {
+                return Ok(None);
+            }
+            let contents = Contents::from_line(s)
+                .unwrap_or_else(|| Err(AocError::parse("expected `<count> <bag> bag(s)`")))?;
+            Ok(Some((contents.bag_type, contents.count)))
+        })
+        .map_err(|err| err.offset_column(head.chars().count()))?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        self.rules.insert(key, bag_counts);
+        Ok(())
+    }
+
+    fn contains_type(&self, target: &String, current: &String) -> bool {
+        match self.rules.get(current) {
+            None => false,
+            Some(bag_types) => bag_types
+                .iter()
+                .any(|(bag_type, _)| bag_type == target || self.contains_type(target, bag_type)),
+        }
+    }
+
+    fn get_num_containing_bag_type(&self, bag_type: &String) -> u32 {
+        self.rules
+            .iter()
+            .fold(0, |acc, t| acc + self.contains_type(bag_type, t.0) as u32)
+    }
+
+    fn count_bags_in_bag_type(&self, bag_type: &String) -> u32 {
+        match self.rules.get(bag_type) {
+            Some(bag_counts) if !bag_counts.is_empty() => {
+                bag_counts.iter().fold(0, |acc, (typ, count)| {
+                    acc + count + count * self.count_bags_in_bag_type(typ)
+                })
+            }
+            _ => 0,
+        }
+    }
+}
+
+fn parse_rules(content: &str) -> Result<BaggageRules, AocError> {
+    let mut rules = BaggageRules::new();
+    for (idx, line) in content.lines().enumerate() {
+        rules
+            .parse_rule(line)
+            .map_err(|err| err.at_line(idx + 1).with_text(line))?;
+    }
+    Ok(rules)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = BaggageRules;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_rules(content)
+    }
+
+    fn part1(rules: &Self::Input) -> Result<u32, AocError> {
+        Ok(rules.get_num_containing_bag_type(&"shiny gold".to_string()))
+    }
+
+    fn part2(rules: &Self::Input) -> Result<u32, AocError> {
+        Ok(rules.count_bags_in_bag_type(&"shiny gold".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day7::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day7::part1(&input).unwrap(), 4);
+        assert_eq!(Day7::part2(&input).unwrap(), 32);
+    }
+
+    #[test]
+    fn nested_example() {
+        let input = Day7::parse(include_str!("../input/input2.txt")).unwrap();
+        assert_eq!(Day7::part2(&input).unwrap(), 126);
+    }
+}
@@ -0,0 +1,180 @@
+use common::error::{parse_lines, AocError};
+use common::line::FromLine;
+use common::solution::Solution;
+use std::collections::HashSet;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Instruction {
+    Acc(i32),
+    Jmp(isize),
+    Nop(isize),
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+impl FromStr for Op {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "acc" => Ok(Op::Acc),
+            "jmp" => Ok(Op::Jmp),
+            "nop" => Ok(Op::Nop),
+            _ => Err("expected `acc`, `jmp` or `nop`"),
+        }
+    }
+}
+
+/// A number written with its sign, as in `+3` or `-7`.
+struct Signed(i32);
+
+impl FromStr for Signed {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.starts_with(['+', '-']) {
+            return Err("expected a signed value".to_owned());
+        }
+        s.parse()
+            .map(Signed)
+            .map_err(|err: ParseIntError| err.to_string())
+    }
+}
+
+#[derive(FromLine)]
+#[pattern(r"(?P<op>\S+)\s(?P<value>\S+)")]
+struct Line {
+    op: Op,
+    value: Signed,
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Line { op, value } = s.parse()?;
+        Ok(match op {
+            Op::Acc => Instruction::Acc(value.0),
+            Op::Jmp => Instruction::Jmp(value.0 as isize),
+            Op::Nop => Instruction::Nop(value.0 as isize),
+        })
+    }
+}
+
+enum Outcome {
+    Terminated(i32),
+    Looped(i32),
+}
+
+fn run(instructions: &[Instruction], swap: Option<usize>) -> Result<Outcome, AocError> {
+    let mut pointer: usize = 0;
+    let mut acc = 0;
+    let mut seen: HashSet<usize> = HashSet::new();
+    while !seen.contains(&pointer) {
+        if pointer >= instructions.len() {
+            return Ok(Outcome::Terminated(acc));
+        }
+        seen.insert(pointer);
+
+        let mut instruction = instructions[pointer];
+        if Some(pointer) == swap {
+            instruction = match instruction {
+                Instruction::Acc(_) => instruction,
+                Instruction::Jmp(v) => Instruction::Nop(v),
+                Instruction::Nop(v) => Instruction::Jmp(v),
+            };
+        }
+
+        match instruction {
+            Instruction::Acc(value) => {
+                acc += value;
+                pointer += 1;
+            }
+            Instruction::Jmp(value) => {
+                let target = if value.is_negative() {
+                    pointer.checked_sub(value.wrapping_abs() as usize)
+                } else {
+                    pointer.checked_add(value as usize)
+                };
+                pointer = target.ok_or_else(|| {
+                    AocError::no_solution(format!(
+                        "The jump at instruction {} leaves the program",
+                        pointer + 1
+                    ))
+                })?;
+            }
+            Instruction::Nop(_) => {
+                pointer += 1;
+            }
+        }
+    }
+
+    Ok(Outcome::Looped(acc))
+}
+
+/// Runs the program with instruction `idx` swapped; a crash counts as a miss.
+fn check(idx: usize, instructions: &[Instruction]) -> Option<i32> {
+    match run(instructions, Some(idx)) {
+        Ok(Outcome::Terminated(acc)) => Some(acc),
+        Ok(Outcome::Looped(_)) | Err(_) => None,
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, |l| l.parse::<Instruction>())
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<i32, AocError> {
+        match run(instructions, None)? {
+            Outcome::Looped(acc) => Ok(acc),
+            Outcome::Terminated(acc) => Err(AocError::no_solution(format!(
+                "The program terminated with {}",
+                acc
+            ))),
+        }
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<i32, AocError> {
+        (0..instructions.len())
+            .find_map(|i| check(i, instructions))
+            .ok_or_else(|| AocError::no_solution("Couldn't find a valid exit point"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day8::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day8::part1(&input).unwrap(), 5);
+        assert_eq!(Day8::part2(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let err = Day8::parse("nop +0\nacc +1\njmp 4\n").unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.column, Some(5));
+        assert_eq!(err.text.as_deref(), Some("jmp 4"));
+
+        let err = Day8::parse("nop +0\nadd +1\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(1));
+    }
+}
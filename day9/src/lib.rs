@@ -0,0 +1,169 @@
+use common::error::{parse_lines, parse_number, AocError};
+use common::solution::Solution;
+use std::cmp;
+use std::collections::{vec_deque, HashMap, VecDeque};
+use std::hash::Hash;
+
+const PREAMBLE: usize = 25;
+
+struct OrderedSet<T> {
+    counts: HashMap<T, u32>,
+    values: VecDeque<T>,
+}
+
+impl<T> OrderedSet<T>
+where
+    T: Copy + Eq + Hash,
+{
+    fn new() -> Self {
+        OrderedSet {
+            counts: HashMap::new(),
+            values: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.values.push_back(value);
+        let entry = self.counts.entry(value).or_insert(0);
+        *entry += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if let Some(value) = self.values.pop_front() {
+            if let Some(count) = self.counts.get_mut(&value) {
+                *count -= 1;
+                // Drop the entry so `contains` only sees values still in the window.
+                if *count == 0 {
+                    self.counts.remove(&value);
+                }
+            }
+            return Some(value);
+        }
+        None
+    }
+
+    fn contains(&self, value: T) -> bool {
+        self.counts.contains_key(&value)
+    }
+
+    fn values(&self) -> vec_deque::Iter<'_, T> {
+        self.values.iter()
+    }
+}
+
+fn find_invalid_num(set: &mut OrderedSet<u64>, nums: &[u64], preamble: usize) -> Option<u64> {
+    for num in nums.iter().skip(preamble) {
+        let is_valid = set
+            .values()
+            .filter(|v| *v <= num)
+            .find(|v| {
+                let target = *num - *v;
+                target != **v && set.contains(target)
+            })
+            .is_some();
+        if !is_valid {
+            return Some(*num);
+        }
+        set.push(*num);
+        set.pop();
+    }
+    None
+}
+
+pub fn find_sum(nums: &[u64], target: u64) -> Option<u64> {
+    // The window is nums[start..=end], and `sum` is its total.
+    let mut start = 0;
+    let mut end = 0;
+    let mut sum = *nums.first()?;
+    loop {
+        if sum == target {
+            let min = nums[start..=end].iter().min().unwrap();
+            let max = nums[start..=end].iter().max().unwrap();
+            return Some(min + max);
+        }
+        // Shrinking a one-number window would leave nothing to sum.
+        if sum < target || start == end {
+            end += 1;
+            sum += nums.get(end)?;
+        } else {
+            sum -= nums[start];
+            start += 1;
+        }
+    }
+}
+
+pub fn find_sum_brute_force(nums: &[u64], target: u64) -> Option<u64> {
+    let mut i = 0;
+    while i < nums.len() {
+        let mut min = u64::MAX;
+        let mut max = u64::MIN;
+        let mut sum = 0;
+        let mut j = i;
+        while j < nums.len() && sum < target {
+            sum += nums[j];
+            min = cmp::min(min, nums[j]);
+            max = cmp::max(max, nums[j]);
+            j += 1;
+        }
+        if sum == target {
+            return Some(min + max);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn get_invalid_num(nums: &[u64], preamble: usize) -> Option<u64> {
+    let mut set: OrderedSet<u64> = OrderedSet::new();
+    for num in nums.iter().take(preamble) {
+        set.push(*num);
+    }
+    find_invalid_num(&mut set, nums, preamble)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, |l| parse_number(l, 1))
+    }
+
+    fn part1(nums: &Self::Input) -> Result<u64, AocError> {
+        get_invalid_num(nums, PREAMBLE)
+            .ok_or_else(|| AocError::no_solution("Couldn't find an invalid num"))
+    }
+
+    fn part2(nums: &Self::Input) -> Result<u64, AocError> {
+        let invalid_num = Self::part1(nums)?;
+        find_sum_brute_force(nums, invalid_num)
+            .ok_or_else(|| AocError::no_solution("Couldn't find a contiguous sum"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        // The example uses a 5-number preamble rather than 25.
+        let nums = Day9::parse(include_str!("../input/input1.txt")).unwrap();
+        let invalid_num = get_invalid_num(&nums, 5).unwrap();
+        assert_eq!(invalid_num, 127);
+        assert_eq!(find_sum_brute_force(&nums, invalid_num), Some(62));
+        assert_eq!(find_sum(&nums, invalid_num), Some(62));
+    }
+
+    #[test]
+    fn sum_windows() {
+        // The largest number is the last one in the window.
+        assert_eq!(find_sum(&[1, 2, 3], 5), Some(5));
+        assert_eq!(find_sum(&[4, 1, 2, 3], 3), Some(3));
+        assert_eq!(find_sum(&[1, 2, 3], 7), None);
+        assert_eq!(find_sum(&[], 7), None);
+    }
+}
@@ -0,0 +1,95 @@
+use common::solution::Part;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct AnswersError {
+    line: usize,
+    message: String,
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+/// One known-good answer: `<day> <part> <input> <answer>`.
+///
+/// `input` names a file in the day's `input/` directory.
+#[derive(Debug, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+fn parse_line(line: &str) -> Result<Expected, String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 4 {
+        return Err(format!("expected 4 fields, found {}", fields.len()));
+    }
+    Ok(Expected {
+        day: fields[0]
+            .parse()
+            .map_err(|_| format!("invalid day: {}", fields[0]))?,
+        part: fields[1].parse()?,
+        input: fields[2].to_owned(),
+        answer: fields[3].to_owned(),
+    })
+}
+
+/// Parses an answers file. Blank lines and `#` comments are ignored.
+pub fn parse(content: &str) -> Result<Vec<Expected>, AnswersError> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, text)| parse_line(text).map_err(|message| AnswersError { line, message }))
+        .collect()
+}
+
+pub fn load(path: &Path) -> Result<Vec<Expected>, AnswersError> {
+    let content = fs::read_to_string(path).map_err(|err| AnswersError {
+        line: 0,
+        message: format!("Failed to read {}: {}", path.display(), err),
+    })?;
+    parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_and_blank_lines() {
+        let expected = parse("# day part file answer\n\n1 2 input.txt 241861950\n").unwrap();
+        assert_eq!(
+            expected,
+            vec![Expected {
+                day: 1,
+                part: Part::Two,
+                input: "input.txt".to_owned(),
+                answer: "241861950".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let err = parse("1 1 input.txt 514579\n\n1 input.txt 514579\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: expected 4 fields, found 3");
+        let err = parse("one 1 input.txt 514579\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: invalid day: one");
+        let err = parse("1 3 input.txt 514579\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: Invalid part: 3");
+    }
+}
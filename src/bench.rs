@@ -0,0 +1,175 @@
+use crate::days::Day;
+use crate::output::millis;
+use common::input::Source;
+use common::solution::{Part, Report};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Changes smaller than this are treated as noise, however large in percent.
+const NOISE_FLOOR_MS: f64 = 1.0;
+
+/// The fastest time seen for each step of one day, in milliseconds.
+///
+/// A part that failed has no timing.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Timing {
+    pub day: u8,
+    pub parse_ms: f64,
+    pub part1_ms: Option<f64>,
+    pub part2_ms: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Baseline {
+    pub days: Vec<Timing>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+        serde_json::from_str(&content)
+            .map_err(|err| format!("Invalid baseline {}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, content + "\n")
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+    }
+
+    fn get(&self, day: u8) -> Option<&Timing> {
+        self.days.iter().find(|timing| timing.day == day)
+    }
+}
+
+fn min(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        _ => a.or(b),
+    }
+}
+
+fn part_ms(report: &Report, part: Part) -> Option<f64> {
+    report
+        .answers
+        .iter()
+        .find(|answer| answer.part == part && answer.outcome.is_ok())
+        .map(|answer| millis(answer.elapsed))
+}
+
+/// Solves `day` against its default input `runs` times, keeping the fastest run of each step.
+pub fn time(day: &Day, runs: usize) -> Result<Timing, String> {
+    let content = day
+        .read_input(&Source::Default)
+        .map_err(|err| err.to_string())?;
+    let name = day.describe_input(&Source::Default);
+    let mut timing: Option<Timing> = None;
+    for _ in 0..runs.max(1) {
+        let report = (day.solve)(&name, &content, Part::BOTH);
+        let run = Timing {
+            day: day.number,
+            parse_ms: millis(report.parse_elapsed),
+            part1_ms: part_ms(&report, Part::One),
+            part2_ms: part_ms(&report, Part::Two),
+        };
+        timing = Some(match timing {
+            None => run,
+            Some(best) => Timing {
+                day: day.number,
+                parse_ms: best.parse_ms.min(run.parse_ms),
+                part1_ms: min(best.part1_ms, run.part1_ms),
+                part2_ms: min(best.part2_ms, run.part2_ms),
+            },
+        });
+    }
+    Ok(timing.unwrap())
+}
+
+fn is_regression(current: f64, previous: f64, threshold: f64) -> bool {
+    current - previous > NOISE_FLOOR_MS && current > previous * (1.0 + threshold / 100.0)
+}
+
+/// Formats one table cell, comparing against the baseline if there is one.
+///
+/// Returns the cell and whether it is a regression.
+fn cell(current: Option<f64>, previous: Option<f64>, threshold: f64) -> (String, bool) {
+    match (current, previous) {
+        (None, _) => ("failed".to_owned(), false),
+        (Some(current), None) => (format!("{:.3}", current), false),
+        (Some(current), Some(previous)) => {
+            let change = if previous > 0.0 {
+                (current - previous) / previous * 100.0
+            } else {
+                0.0
+            };
+            let regression = is_regression(current, previous, threshold);
+            let marker = if regression { " !" } else { "" };
+            (
+                format!("{:.3} ({:+.1}%){}", current, change, marker),
+                regression,
+            )
+        }
+    }
+}
+
+/// Prints a table of `timings`, flagging steps more than `threshold` percent
+/// slower than in `baseline`. Returns the number of regressions.
+pub fn report(timings: &[Timing], baseline: Option<&Baseline>, threshold: f64) -> usize {
+    println!(
+        "{:>3}  {:>24}  {:>24}  {:>24}",
+        "Day", "Parse (ms)", "Part 1 (ms)", "Part 2 (ms)"
+    );
+    let mut regressions = Vec::new();
+    for timing in timings {
+        let previous = baseline.and_then(|baseline| baseline.get(timing.day));
+        let cells = [
+            ("parse", Some(timing.parse_ms), previous.map(|p| p.parse_ms)),
+            ("part 1", timing.part1_ms, previous.and_then(|p| p.part1_ms)),
+            ("part 2", timing.part2_ms, previous.and_then(|p| p.part2_ms)),
+        ];
+        let mut row = format!("{:>3}", timing.day);
+        for (step, current, previous) in cells.iter() {
+            let (text, regression) = cell(*current, *previous, threshold);
+            if regression {
+                regressions.push(format!(
+                    "day {} {}: {:.3} ms -> {:.3} ms",
+                    timing.day,
+                    step,
+                    previous.unwrap(),
+                    current.unwrap()
+                ));
+            }
+            row.push_str(&format!("  {:>24}", text));
+        }
+        println!("{}", row);
+    }
+
+    if !regressions.is_empty() {
+        println!(
+            "\n{} regression(s) over {}% slower than the baseline:",
+            regressions.len(),
+            threshold
+        );
+        for regression in &regressions {
+            println!(" - {}", regression);
+        }
+    }
+    regressions.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regressions() {
+        assert!(is_regression(130.0, 100.0, 20.0));
+        assert!(!is_regression(110.0, 100.0, 20.0));
+        assert!(!is_regression(90.0, 100.0, 20.0));
+        // Tripled, but by less than the noise floor.
+        assert!(!is_regression(0.3, 0.1, 20.0));
+        assert!(is_regression(2.5, 1.0, 20.0));
+    }
+}
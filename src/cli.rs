@@ -0,0 +1,306 @@
+use crate::output::Format;
+use common::input::Source;
+use common::solution::Part;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct UsageError(String);
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<Part>,
+        input: Source,
+        format: Format,
+        /// Print the puzzle's walkthrough before the answers.
+        trace: bool,
+    },
+    /// Every day against its default input, in parallel.
+    RunAll {
+        part: Option<Part>,
+        format: Format,
+        jobs: usize,
+        timeout: Duration,
+    },
+    Verify {
+        day: Option<u8>,
+        answers: PathBuf,
+    },
+    New {
+        day: u8,
+    },
+    Bench {
+        day: Option<u8>,
+        runs: usize,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+    },
+}
+
+pub fn usage() {
+    eprintln!("Usage: aoc2020 <command> [options]");
+    eprintln!(" Commands:");
+    eprintln!(" - run --day <n> [--part <1|2>] [--input <path|->]");
+    eprintln!("         [--format <text|json>] [--trace]");
+    eprintln!(" - run --all [--part <1|2>] [--jobs <n>] [--timeout <seconds>]");
+    eprintln!("         [--format <text|json>]");
+    eprintln!(" - verify [--day <n>] [--answers <path>]");
+    eprintln!(" - new --day <n>");
+    eprintln!(" - bench [--day <n>] [--runs <n>] [--save <path>] [--baseline <path>]");
+    eprintln!("         [--threshold <percent>]");
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, UsageError> {
+    let value = value.ok_or_else(|| UsageError(format!("{} requires a value", flag)))?;
+    value
+        .parse()
+        .map_err(|_| UsageError(format!("Invalid value for {}: {}", flag, value)))
+}
+
+fn parse_run<I>(mut args: I) -> Result<Command, UsageError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = Source::Default;
+    let mut format = Format::Text;
+    let mut jobs = None;
+    let mut timeout = None;
+    let mut trace = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_value(&arg, args.next())?),
+            "--all" => all = true,
+            "--part" => part = Some(parse_value(&arg, args.next())?),
+            "--input" => {
+                let value: String = parse_value(&arg, args.next())?;
+                input = Source::from_arg(&value);
+            }
+            "--format" => format = parse_value(&arg, args.next())?,
+            "--jobs" => jobs = Some(parse_value(&arg, args.next())?),
+            "--timeout" => timeout = Some(parse_value::<f64>(&arg, args.next())?),
+            "--trace" => trace = true,
+            _ => return Err(UsageError(format!("Unexpected argument: {}", arg))),
+        }
+    }
+
+    if all {
+        if day.is_some() || input != Source::Default {
+            return Err(UsageError(
+                "--all can't be combined with --day or --input".to_owned(),
+            ));
+        }
+        if trace {
+            return Err(UsageError("--trace only applies to --day".to_owned()));
+        }
+        let timeout = timeout.unwrap_or(30.0);
+        let invalid = || UsageError(format!("Invalid value for --timeout: {}", timeout));
+        if timeout <= 0.0 {
+            return Err(invalid());
+        }
+        let timeout = Duration::try_from_secs_f64(timeout).map_err(|_| invalid())?;
+        return Ok(Command::RunAll {
+            part,
+            format,
+            jobs: jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())),
+            timeout,
+        });
+    }
+    if jobs.is_some() || timeout.is_some() {
+        return Err(UsageError(
+            "--jobs and --timeout only apply to --all".to_owned(),
+        ));
+    }
+    if trace && format != Format::Text {
+        return Err(UsageError(
+            "--trace can't be combined with --format json".to_owned(),
+        ));
+    }
+    let day = day.ok_or_else(|| UsageError("run requires --day or --all".to_owned()))?;
+    Ok(Command::Run {
+        day,
+        part,
+        input,
+        format,
+        trace,
+    })
+}
+
+fn parse_verify<I>(mut args: I) -> Result<Command, UsageError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut answers = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt"));
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_value(&arg, args.next())?),
+            "--answers" => answers = parse_value(&arg, args.next())?,
+            _ => return Err(UsageError(format!("Unexpected argument: {}", arg))),
+        }
+    }
+    Ok(Command::Verify { day, answers })
+}
+
+fn parse_new<I>(mut args: I) -> Result<Command, UsageError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_value(&arg, args.next())?),
+            _ => return Err(UsageError(format!("Unexpected argument: {}", arg))),
+        }
+    }
+    let day = day.ok_or_else(|| UsageError("new requires --day".to_owned()))?;
+    Ok(Command::New { day })
+}
+
+fn parse_bench<I>(mut args: I) -> Result<Command, UsageError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut runs = 1;
+    let mut save = None;
+    let mut baseline = None;
+    let mut threshold = 20.0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_value(&arg, args.next())?),
+            "--runs" => runs = parse_value(&arg, args.next())?,
+            "--save" => save = Some(parse_value(&arg, args.next())?),
+            "--baseline" => baseline = Some(parse_value(&arg, args.next())?),
+            "--threshold" => threshold = parse_value(&arg, args.next())?,
+            _ => return Err(UsageError(format!("Unexpected argument: {}", arg))),
+        }
+    }
+    Ok(Command::Bench {
+        day,
+        runs,
+        save,
+        baseline,
+        threshold,
+    })
+}
+
+pub fn parse<I>(mut args: I) -> Result<Command, UsageError>
+where
+    I: Iterator<Item = String>,
+{
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("verify") => parse_verify(args),
+        Some("new") => parse_new(args),
+        Some("bench") => parse_bench(args),
+        Some(command) => Err(UsageError(format!("Unknown command: {}", command))),
+        None => Err(UsageError("Missing command".to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &str) -> Result<Command, String> {
+        parse(args.split_whitespace().map(str::to_owned)).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn run() {
+        assert_eq!(
+            parse_args("run --day 3 --part 2 --input - --format json"),
+            Ok(Command::Run {
+                day: 3,
+                part: Some(Part::Two),
+                input: Source::Stdin,
+                format: Format::Json,
+                trace: false,
+            })
+        );
+        assert_eq!(
+            parse_args("run --day 22 --trace"),
+            Ok(Command::Run {
+                day: 22,
+                part: None,
+                input: Source::Default,
+                format: Format::Text,
+                trace: true,
+            })
+        );
+        assert_eq!(
+            parse_args("run --all --jobs 2 --timeout 1.5"),
+            Ok(Command::RunAll {
+                part: None,
+                format: Format::Text,
+                jobs: 2,
+                timeout: Duration::from_millis(1500),
+            })
+        );
+    }
+
+    #[test]
+    fn conflicting_flags() {
+        assert_eq!(
+            parse_args("run --all --day 3"),
+            Err("--all can't be combined with --day or --input".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --day 3 --jobs 2"),
+            Err("--jobs and --timeout only apply to --all".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --all --trace"),
+            Err("--trace only applies to --day".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --day 22 --trace --format json"),
+            Err("--trace can't be combined with --format json".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --all --timeout 0"),
+            Err("Invalid value for --timeout: 0".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --all --timeout 1e20"),
+            Err("Invalid value for --timeout: 100000000000000000000".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --all --timeout NaN"),
+            Err("Invalid value for --timeout: NaN".to_owned())
+        );
+    }
+
+    #[test]
+    fn bad_arguments() {
+        assert_eq!(parse_args(""), Err("Missing command".to_owned()));
+        assert_eq!(parse_args("fly"), Err("Unknown command: fly".to_owned()));
+        assert_eq!(
+            parse_args("run --part"),
+            Err("--part requires a value".to_owned())
+        );
+        assert_eq!(
+            parse_args("run --day x"),
+            Err("Invalid value for --day: x".to_owned())
+        );
+        assert_eq!(
+            parse_args("new --day 3 --part 1"),
+            Err("Unexpected argument: --part".to_owned())
+        );
+    }
+}
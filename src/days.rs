@@ -0,0 +1,84 @@
+use common::error::AocError;
+use common::input::Source;
+use common::solution;
+use common::solution::{Part, Solution, Solver, Tracer};
+use std::path::{Path, PathBuf};
+
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    /// The parts the puzzle has.
+    pub parts: &'static [Part],
+    /// Prints the puzzle's walkthrough, for the days that support `--trace`.
+    pub trace: Option<Tracer>,
+}
+
+impl Day {
+    pub fn crate_dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("day{}", self.number))
+    }
+
+    /// Resolves a file name within the day's `input/` directory.
+    pub fn input_path(&self, name: &str) -> PathBuf {
+        self.crate_dir().join("input").join(name)
+    }
+
+    pub fn describe_input(&self, source: &Source) -> String {
+        source.describe(&self.crate_dir())
+    }
+
+    pub fn read_input(&self, source: &Source) -> Result<String, AocError> {
+        source.read(&self.crate_dir())
+    }
+}
+
+macro_rules! day {
+    ($number:expr, $solution:ty) => {
+        day!($number, $solution, None)
+    };
+    ($number:expr, $solution:ty, trace: $trace:expr) => {
+        day!($number, $solution, Some($trace))
+    };
+    ($number:expr, $solution:ty, $trace:expr) => {
+        Day {
+            number: $number,
+            solve: solution::solve::<$solution>,
+            parts: <$solution as Solution>::PARTS,
+            trace: $trace,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8),
+    day!(9, day9::Day9),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(15, day15::Day15),
+    day!(16, day16::Day16),
+    day!(17, day17::Day17),
+    day!(18, day18::Day18),
+    day!(19, day19::Day19),
+    day!(20, day20::Day20),
+    day!(21, day21::Day21),
+    day!(22, day22::Day22, trace: day22::trace),
+    day!(23, day23::Day23, trace: |content| {
+        day23::trace(content, day23::Rules::PART1.moves)
+    }),
+    day!(24, day24::Day24),
+    day!(25, day25::Day25),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
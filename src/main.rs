@@ -1,3 +1,200 @@
+mod answers;
+mod bench;
+mod cli;
+mod days;
+mod output;
+mod parallel;
+mod scaffold;
+mod verify;
+
+use bench::Baseline;
+use cli::Command;
+use common::input::Source;
+use common::solution::Part;
+use output::Format;
+use std::env::args;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+fn run(day: u8, part: Option<Part>, input: &Source, format: Format, trace: bool) {
+    let day = match days::find(day) {
+        Some(day) => day,
+        None => {
+            eprintln!("No solution for day {}", day);
+            process::exit(1);
+        }
+    };
+
+    let parts = match part {
+        Some(part) if !day.parts.contains(&part) => {
+            eprintln!("Day {} has no part {}", day.number, part);
+            process::exit(1);
+        }
+        Some(part) => vec![part],
+        None => day.parts.to_vec(),
+    };
+    let tracer = match day.trace {
+        None if trace => {
+            eprintln!("Day {} has no trace", day.number);
+            process::exit(1);
+        }
+        tracer => tracer.filter(|_| trace),
+    };
+    let content = match day.read_input(input) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+    let name = day.describe_input(input);
+    let mut failed = false;
+    if let Some(tracer) = tracer {
+        match tracer(&content) {
+            Ok(trace) => println!("{}", trace),
+            Err(err) => {
+                eprintln!("Couldn't trace: {}", err.in_file(&name));
+                failed = true;
+            }
+        }
+    }
+    for answer in (day.solve)(&name, &content, &parts).answers {
+        output::print(format, day.number, &answer, &name);
+        failed |= answer.outcome.is_err();
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_all(part: Option<Part>, format: Format, jobs: usize, timeout: Duration) {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    let days: Vec<_> = days::DAYS.iter().collect();
+    let mut failed = false;
+    for report in parallel::solve_all(&days, &parts, jobs, timeout) {
+        for answer in &report.answers {
+            output::print(format, report.day.number, answer, &report.input);
+            failed |= answer.outcome.is_err();
+        }
+    }
+    // Exit rather than return, so solvers that timed out don't keep us waiting.
+    process::exit(if failed { 1 } else { 0 });
+}
+
+fn verify(day: Option<u8>, answers: &Path) {
+    let expected = match answers::load(answers) {
+        Ok(expected) => expected,
+        Err(err) => {
+            eprintln!("{}: {}", answers.display(), err);
+            process::exit(1);
+        }
+    };
+    let expected: Vec<_> = expected
+        .into_iter()
+        .filter(|e| day.is_none_or(|day| e.day == day))
+        .collect();
+    if expected.is_empty() {
+        match day {
+            Some(day) => eprintln!("No answers for day {} in {}", day, answers.display()),
+            None => eprintln!("No answers in {}", answers.display()),
+        }
+        process::exit(1);
+    }
+    if !verify::verify(&expected) {
+        process::exit(1);
+    }
+}
+
+fn new(day: u8) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if let Err(err) = scaffold::new_day(root, day) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+    println!("Created day{}. Next:", day);
+    println!(" - paste the puzzle input into day{}/input/input.txt", day);
+    println!(" - paste the example into day{}/input/input1.txt", day);
+    println!(" - fill in the expected answers and un-ignore the example test");
+}
+
+fn bench(
+    day: Option<u8>,
+    runs: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) {
+    if let Some(day) = day.filter(|&day| days::find(day).is_none()) {
+        eprintln!("No solution for day {}", day);
+        process::exit(1);
+    }
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build; use `cargo run --release`\n");
+    }
+    let baseline = baseline.map(|path| {
+        Baseline::load(&path).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        })
+    });
+
+    let mut timings = Vec::new();
+    for day in days::DAYS
+        .iter()
+        .filter(|d| day.is_none_or(|day| d.number == day))
+    {
+        match bench::time(day, runs) {
+            Ok(timing) => timings.push(timing),
+            Err(err) => eprintln!("Skipping day {}: {}", day.number, err),
+        }
+    }
+
+    let num_regressions = bench::report(&timings, baseline.as_ref(), threshold);
+    if let Some(path) = save {
+        let baseline = Baseline { days: timings };
+        if let Err(err) = baseline.save(&path) {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+        println!("\nSaved baseline to {}", path.display());
+    }
+    if num_regressions > 0 {
+        process::exit(1);
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    match cli::parse(args().skip(1)) {
+        Ok(Command::Run {
+            day,
+            part,
+            input,
+            format,
+            trace,
+        }) => run(day, part, &input, format, trace),
+        Ok(Command::RunAll {
+            part,
+            format,
+            jobs,
+            timeout,
+        }) => run_all(part, format, jobs, timeout),
+        Ok(Command::Verify { day, answers }) => verify(day, &answers),
+        Ok(Command::New { day }) => new(day),
+        Ok(Command::Bench {
+            day,
+            runs,
+            save,
+            baseline,
+            threshold,
+        }) => bench(day, runs, save, baseline, threshold),
+        Err(err) => {
+            eprintln!("{}\n", err);
+            cli::usage();
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use common::solution::Answer;
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Milliseconds, to the nearest microsecond.
+pub fn millis(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Format {
+    Text,
+    /// One JSON record per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {}", s)),
+        }
+    }
+}
+
+/// A solver result in the shape other tools consume with `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub elapsed_ms: f64,
+    pub input: &'a str,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(day: u8, answer: &'a Answer, input: &'a str) -> Record<'a> {
+        Record {
+            day,
+            part: answer.part.number(),
+            answer: answer.outcome.as_ref().ok().map(|value| value.as_str()),
+            error: answer.outcome.as_ref().err().map(|err| err.to_string()),
+            elapsed_ms: millis(answer.elapsed),
+            input,
+        }
+    }
+}
+
+/// Prints one answer; failures go to stderr in text mode.
+pub fn print(format: Format, day: u8, answer: &Answer, input: &str) {
+    match format {
+        Format::Text => match &answer.outcome {
+            Ok(value) => println!("Day {} part {}: {}", day, answer.part, value),
+            Err(err) => eprintln!("Day {} part {} failed: {}", day, answer.part, err),
+        },
+        Format::Json => {
+            let record = Record::new(day, answer, input);
+            println!("{}", serde_json::to_string(&record).unwrap());
+        }
+    }
+}
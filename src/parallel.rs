@@ -0,0 +1,107 @@
+use crate::days::Day;
+use common::error::AocError;
+use common::input::Source;
+use common::solution::{Answer, Part};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+/// One day's answers, and the input they came from.
+pub struct DayReport {
+    pub day: &'static Day,
+    pub input: String,
+    pub answers: Vec<Answer>,
+}
+
+/// Fails every one of `parts` with `err`.
+fn failed(parts: &[Part], err: AocError, elapsed: Duration) -> Vec<Answer> {
+    parts
+        .iter()
+        .map(|&part| Answer {
+            part,
+            outcome: Err(err.clone()),
+            elapsed,
+        })
+        .collect()
+}
+
+/// Solves `day` against its default input, giving up after `timeout`.
+///
+/// Solvers can't be interrupted, so each one runs on a thread of its own; one
+/// that times out is left to finish (or not) in the background.
+fn solve_with_timeout(day: &'static Day, parts: &[Part], timeout: Duration) -> DayReport {
+    let parts: Vec<Part> = parts
+        .iter()
+        .filter(|part| day.parts.contains(part))
+        .copied()
+        .collect();
+    let parts = parts.as_slice();
+    let input = day.describe_input(&Source::Default);
+    let content = match day.read_input(&Source::Default) {
+        Ok(content) => content,
+        Err(err) => {
+            return DayReport {
+                day,
+                input,
+                answers: failed(parts, err, Duration::default()),
+            }
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let (name, owned_parts) = (input.clone(), parts.to_vec());
+    thread::spawn(move || {
+        // Nobody is listening any more if this day timed out.
+        let _ = sender.send((day.solve)(&name, &content, &owned_parts));
+    });
+    let answers = match receiver.recv_timeout(timeout) {
+        Ok(report) => report.answers,
+        Err(RecvTimeoutError::Timeout) => {
+            let message = format!("Timed out after {:.1}s", timeout.as_secs_f64());
+            failed(parts, AocError::timeout(message).in_file(&input), timeout)
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            let err = AocError::panic("The solver thread died").in_file(&input);
+            failed(parts, err, Duration::default())
+        }
+    };
+    DayReport {
+        day,
+        input,
+        answers,
+    }
+}
+
+/// Solves `days` on a pool of `jobs` threads, each day limited to `timeout`
+/// of wall-clock time. Reports come back in the order of `days`.
+pub fn solve_all(
+    days: &[&'static Day],
+    parts: &[Part],
+    jobs: usize,
+    timeout: Duration,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let (next, sender) = (&next, sender.clone());
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::SeqCst);
+                if idx >= days.len() {
+                    break;
+                }
+                let report = solve_with_timeout(days[idx], parts, timeout);
+                sender.send((idx, report)).unwrap();
+            });
+        }
+    });
+    drop(sender);
+
+    let mut reports: Vec<Option<DayReport>> = days.iter().map(|_| None).collect();
+    for (idx, report) in receiver {
+        reports[idx] = Some(report);
+    }
+    reports.into_iter().map(Option::unwrap).collect()
+}
@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::Path;
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+authors = ["Ian Hoffman <ianhoffman10@gmail.com>"]
+edition = "2018"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+"#;
+
+const MAIN_RS: &str = r#"use common::{input, solution};
+use day{day}::Day{day};
+
+fn main() {
+    let (name, content) = input::load(env!("CARGO_MANIFEST_DIR"));
+    solution::print::<Day{day}>(&name, &content);
+}
+"#;
+
+const LIB_RS: &str = r#"use common::error::{parse_lines, AocError};
+use common::solution::Solution;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, AocError> {
+        parse_lines(content, |line| Ok(line.to_owned()))
+    }
+
+    fn part1(_input: &Self::Input) -> Result<usize, AocError> {
+        Err(AocError::unsupported("part 1 isn't solved yet"))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize, AocError> {
+        Err(AocError::unsupported("part 2 isn't solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs the puzzle's example in input/input1.txt"]
+    fn example() {
+        let input = Day{day}::parse(include_str!("../input/input1.txt")).unwrap();
+        assert_eq!(Day{day}::part1(&input).unwrap(), 0);
+        assert_eq!(Day{day}::part2(&input).unwrap(), 0);
+    }
+}
+"#;
+
+/// Inserts `line(day)` among the other days' `line(n)`, keeping them sorted.
+fn register<F>(content: &str, day: u8, line: F) -> Result<String, String>
+where
+    F: Fn(u8) -> String,
+{
+    let new_line = line(day);
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.contains(&new_line.as_str()) {
+        return Err(format!("Day {} is already registered", day));
+    }
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, l)| (1..=25).find(|&n| *l == line(n)).map(|n| (idx, n)))
+        .collect();
+    let idx = match days.iter().rev().find(|(_, n)| *n < day) {
+        Some((idx, _)) => idx + 1,
+        None => days
+            .first()
+            .map(|(idx, _)| *idx)
+            .ok_or_else(|| format!("Couldn't find where to register day {}", day))?,
+    };
+
+    let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    lines.insert(idx, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+/// Generates `root/dayN` and registers it with the workspace and the runner.
+///
+/// Registration is checked before anything is written, so a day that's
+/// already (partly) set up is left alone.
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {}", day));
+    }
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let manifest = root.join("Cargo.toml");
+    let registry = root.join("src").join("days.rs");
+    let dependency = |n: u8| format!("day{} = {{ path = \"day{}\" }}", n, n);
+    let member = |n: u8| format!("    \"day{}\",", n);
+    let entry = |n: u8| format!("    day!({}, day{}::Day{}),", n, n, n);
+    let new_manifest = register(&read(&manifest)?, day, dependency)
+        .and_then(|content| register(&content, day, member))
+        .map_err(|err| format!("{}: {}", manifest.display(), err))?;
+    let new_registry = register(&read(&registry)?, day, entry)
+        .map_err(|err| format!("{}: {}", registry.display(), err))?;
+
+    let input = dir.join("input");
+    fs::create_dir_all(dir.join("src"))
+        .and_then(|_| fs::create_dir_all(&input))
+        .map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+    let fill = |template: &str| template.replace("{day}", &day.to_string());
+    write(&dir.join("Cargo.toml"), &fill(CARGO_TOML))?;
+    write(&dir.join("src").join("main.rs"), &fill(MAIN_RS))?;
+    write(&dir.join("src").join("lib.rs"), &fill(LIB_RS))?;
+    write(&input.join("input.txt"), "")?;
+    write(&input.join("input1.txt"), "")?;
+    write(&manifest, &new_manifest)?;
+    write(&registry, &new_registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    fn member(n: u8) -> String {
+        format!("    \"day{}\",", n)
+    }
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let content = "members = [\n    \"common\",\n    \"day2\",\n    \"day11\",\n]\n";
+        assert_eq!(
+            register(content, 3, member).unwrap(),
+            "members = [\n    \"common\",\n    \"day2\",\n    \"day3\",\n    \"day11\",\n]\n"
+        );
+        // `day1` mustn't be mistaken for a prefix of `day11`.
+        assert_eq!(
+            register(content, 1, member).unwrap(),
+            "members = [\n    \"common\",\n    \"day1\",\n    \"day2\",\n    \"day11\",\n]\n"
+        );
+        assert_eq!(
+            register(content, 25, member).unwrap(),
+            "members = [\n    \"common\",\n    \"day2\",\n    \"day11\",\n    \"day25\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn register_needs_somewhere_to_go() {
+        let err = register("members = [\n    \"common\",\n]\n", 3, member).unwrap_err();
+        assert_eq!(err, "Couldn't find where to register day 3");
+        let err = register("members = [\n    \"day3\",\n]\n", 3, member).unwrap_err();
+        assert_eq!(err, "Day 3 is already registered");
+    }
+
+    #[test]
+    fn new_day_in_a_copy() {
+        let root = env::temp_dir().join(format!("aoc2020-scaffold-{}", process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"day1\" }\n\n[workspace]\nmembers = [\n    \"day1\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src").join("days.rs"),
+            "pub const DAYS: &[Day] = &[\n    day!(1, day1::Day1),\n];\n",
+        )
+        .unwrap();
+
+        let result = new_day(&root, 2);
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let registry = fs::read_to_string(root.join("src").join("days.rs")).unwrap();
+        let lib = fs::read_to_string(root.join("day2").join("src").join("lib.rs")).unwrap();
+        let again = new_day(&root, 2);
+        let out_of_range = new_day(&root, 26);
+        fs::remove_dir_all(&root).unwrap();
+
+        result.unwrap();
+        assert!(manifest.contains("day1 = { path = \"day1\" }\nday2 = { path = \"day2\" }\n"));
+        assert!(manifest.contains("    \"day1\",\n    \"day2\",\n"));
+        assert!(registry.contains("    day!(1, day1::Day1),\n    day!(2, day2::Day2),\n"));
+        assert!(lib.contains("impl Solution for Day2 {"));
+        assert!(again.unwrap_err().ends_with("day2 already exists"));
+        assert_eq!(out_of_range.unwrap_err(), "There is no day 26");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::answers::Expected;
+use crate::days;
+use common::input::Source;
+use common::solution::Part;
+
+enum Status {
+    Pass,
+    Fail { actual: String },
+    Error(String),
+}
+
+struct Check<'a> {
+    expected: &'a Expected,
+    status: Status,
+}
+
+/// Runs every solver named in `expected`, parsing each input only once.
+fn check_all(expected: &[Expected]) -> Vec<Check<'_>> {
+    let mut checks: Vec<Check<'_>> = Vec::new();
+    let mut done = vec![false; expected.len()];
+    for i in 0..expected.len() {
+        if done[i] {
+            continue;
+        }
+        let (day, input) = (expected[i].day, &expected[i].input);
+        let group: Vec<usize> = (i..expected.len())
+            .filter(|&j| expected[j].day == day && expected[j].input == *input)
+            .collect();
+        let parts: Vec<Part> = group.iter().map(|&j| expected[j].part).collect();
+
+        let report = match days::find(day) {
+            None => Err(format!("No solution for day {}", day)),
+            Some(solution) => {
+                let source = Source::Path(solution.input_path(input));
+                solution
+                    .read_input(&source)
+                    .map(|content| {
+                        (solution.solve)(&solution.describe_input(&source), &content, &parts)
+                    })
+                    .map_err(|err| err.to_string())
+            }
+        };
+
+        for &j in &group {
+            done[j] = true;
+            let status = match &report {
+                Err(message) => Status::Error(message.clone()),
+                Ok(report) => match report
+                    .answers
+                    .iter()
+                    .find(|answer| answer.part == expected[j].part)
+                    .map(|answer| &answer.outcome)
+                {
+                    None => Status::Error(format!("Day {} has no part {}", day, expected[j].part)),
+                    Some(Ok(actual)) if *actual == expected[j].answer => Status::Pass,
+                    Some(Ok(actual)) => Status::Fail {
+                        actual: actual.clone(),
+                    },
+                    Some(Err(err)) => Status::Error(err.to_string()),
+                },
+            };
+            checks.push(Check {
+                expected: &expected[j],
+                status,
+            });
+        }
+    }
+    checks.sort_by_key(|check| (check.expected.day, check.expected.part));
+    checks
+}
+
+/// Prints a pass/fail table and returns whether every answer matched.
+pub fn verify(expected: &[Expected]) -> bool {
+    let checks = check_all(expected);
+    let width = checks
+        .iter()
+        .map(|check| check.expected.input.len())
+        .max()
+        .unwrap_or(0)
+        .max("Input".len());
+
+    println!("Day  Part  {:<width$}  Result", "Input", width = width);
+    let mut num_failed = 0;
+    for check in &checks {
+        let result = match &check.status {
+            Status::Pass => "ok".to_owned(),
+            Status::Fail { actual } => {
+                format!("FAIL  expected {}, got {}", check.expected.answer, actual)
+            }
+            Status::Error(message) => format!("ERROR {}", message),
+        };
+        if !matches!(check.status, Status::Pass) {
+            num_failed += 1;
+        }
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {}",
+            check.expected.day,
+            check.expected.part,
+            check.expected.input,
+            result,
+            width = width
+        );
+    }
+    println!(
+        "\n{} passed, {} failed",
+        checks.len() - num_failed,
+        num_failed
+    );
+    num_failed == 0
+}